@@ -0,0 +1,409 @@
+//! Derive macros for the [`boow`] crate.
+//!
+//! Do not use this crate directly. Enable the `derive` feature of [`boow`]
+//! instead.
+//!
+//! [`boow`]: https://docs.rs/boow
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Lifetime of the generated projection. Chosen so as not to clash with the
+/// lifetimes of the deriving struct.
+const LIFETIME: &str = "'__boow";
+
+/// Derive `boow::ProjectBow`, generating a `<Name>Projection` struct whose
+/// fields are each wrapped in their own `boow::Bow`.
+#[proc_macro_derive(ProjectBow)]
+pub fn derive_project_bow(input: TokenStream) -> TokenStream {
+    let code = match Struct::parse(input) {
+        Ok(s) => s.project_bow(),
+        Err(msg) => format!("compile_error!({:?});", msg),
+    };
+    code.parse().unwrap()
+}
+
+struct Struct {
+    vis: String,
+    name: String,
+    generics: Vec<Vec<TokenTree>>,
+    where_clause: Vec<TokenTree>,
+    fields: Fields,
+}
+
+enum Fields {
+    Named(Vec<Field>),
+    Unnamed(Vec<Field>),
+}
+
+struct Field {
+    vis: String,
+    name: String,
+    ty: String,
+}
+
+impl Struct {
+    fn parse(input: TokenStream) -> Result<Self, String> {
+        let mut tokens = input.into_iter().peekable();
+        skip_attributes(&mut tokens);
+        let vis = parse_visibility(&mut tokens);
+        match tokens.next() {
+            Some(TokenTree::Ident(ref ident)) if ident.to_string() == "struct" => {}
+            _ => return Err("`ProjectBow` can only be derived for structs".to_owned()),
+        }
+        let name = match tokens.next() {
+            Some(TokenTree::Ident(ident)) => ident.to_string(),
+            _ => return Err("expected struct name".to_owned()),
+        };
+
+        let mut generics = Vec::new();
+        if is_punct(tokens.peek(), '<') {
+            tokens.next();
+            let mut inner = Vec::new();
+            let mut depth = 0;
+            let mut prev_dash = false;
+            loop {
+                let token = match tokens.next() {
+                    Some(token) => token,
+                    None => return Err("unclosed generics".to_owned()),
+                };
+                let mut dash = false;
+                if let TokenTree::Punct(ref p) = token {
+                    match p.as_char() {
+                        '<' => depth += 1,
+                        '>' if !prev_dash && depth == 0 => break,
+                        '>' if !prev_dash => depth -= 1,
+                        '-' => dash = p.spacing() == Spacing::Joint,
+                        _ => {}
+                    }
+                }
+                prev_dash = dash;
+                inner.push(token);
+            }
+            generics = split_top_level(inner);
+        }
+
+        let mut where_clause = Vec::new();
+        let fields;
+        loop {
+            match tokens.next() {
+                Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Brace => {
+                    fields = Fields::Named(parse_fields(group.stream(), true)?);
+                    break;
+                }
+                Some(TokenTree::Group(ref group))
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    fields = Fields::Unnamed(parse_fields(group.stream(), false)?);
+                    where_clause.extend(tokens.by_ref().take_while(|t| !is_punct(Some(t), ';')));
+                    break;
+                }
+                Some(token) => where_clause.push(token),
+                None => return Err("`ProjectBow` cannot be derived for unit structs".to_owned()),
+            }
+        }
+        if fields.is_empty() {
+            return Err("`ProjectBow` cannot be derived for structs without fields".to_owned());
+        }
+
+        Ok(Struct {
+            vis,
+            name,
+            generics,
+            where_clause,
+            fields,
+        })
+    }
+
+    fn project_bow(&self) -> String {
+        let projection = format!("{}Projection", self.name);
+
+        // Generic parameters without their defaults, e.g. `'a, T: Clone`.
+        let mut impl_generics = vec![LIFETIME.to_owned()];
+        // Generic arguments, e.g. `'a, T`.
+        let mut ty_generics = Vec::new();
+        for param in &self.generics {
+            impl_generics.push(without_default(param));
+            ty_generics.push(generic_argument(param));
+        }
+        let impl_generics = impl_generics.join(", ");
+        let ty_generics = ty_generics.join(", ");
+        let projection_generics = if ty_generics.is_empty() {
+            LIFETIME.to_owned()
+        } else {
+            format!("{}, {}", LIFETIME, ty_generics)
+        };
+
+        let where_clause = self
+            .where_clause
+            .iter()
+            .cloned()
+            .collect::<TokenStream>()
+            .to_string();
+        let impl_where_clause = if where_clause.is_empty() {
+            format!("where Self: {}", LIFETIME)
+        } else if where_clause.trim_end().ends_with(',') {
+            format!("{} Self: {}", where_clause, LIFETIME)
+        } else {
+            format!("{}, Self: {}", where_clause, LIFETIME)
+        };
+
+        let (definition, owned, borrowed) = match self.fields {
+            Fields::Named(ref fields) => {
+                let names = fields
+                    .iter()
+                    .map(|f| f.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let definition = format!(
+                    "{vis} struct {projection}<{generics}> {where_clause} {{ {fields} }}",
+                    vis = self.vis,
+                    projection = projection,
+                    generics = impl_generics,
+                    where_clause = where_clause,
+                    fields = fields
+                        .iter()
+                        .map(|f| format!(
+                            "{} {}: ::boow::Bow<{}, {}>,",
+                            f.vis, f.name, LIFETIME, f.ty
+                        ))
+                        .collect::<String>(),
+                );
+                let construct = |variant: &str| {
+                    format!(
+                        "{} {{ {} }}",
+                        projection,
+                        fields
+                            .iter()
+                            .map(|f| format!("{0}: ::boow::Bow::{1}({0}),", f.name, variant))
+                            .collect::<String>()
+                    )
+                };
+                let owned = format!(
+                    "::boow::Bow::Owned({} {{ {} }}) => {},",
+                    self.name,
+                    names,
+                    construct("Owned")
+                );
+                let borrowed = format!(
+                    "::boow::Bow::Borrowed({} {{ {} }}) => {},",
+                    self.name,
+                    names,
+                    construct("Borrowed")
+                );
+                (definition, owned, borrowed)
+            }
+            Fields::Unnamed(ref fields) => {
+                let names = (0..fields.len())
+                    .map(|i| format!("__field{}", i))
+                    .collect::<Vec<_>>();
+                let definition = format!(
+                    "{vis} struct {projection}<{generics}>({fields}) {where_clause};",
+                    vis = self.vis,
+                    projection = projection,
+                    generics = impl_generics,
+                    fields = fields
+                        .iter()
+                        .map(|f| format!("{} ::boow::Bow<{}, {}>,", f.vis, LIFETIME, f.ty))
+                        .collect::<String>(),
+                    where_clause = where_clause,
+                );
+                let construct = |variant: &str| {
+                    format!(
+                        "{}({})",
+                        projection,
+                        names
+                            .iter()
+                            .map(|name| format!("::boow::Bow::{}({}),", variant, name))
+                            .collect::<String>()
+                    )
+                };
+                let owned = format!(
+                    "::boow::Bow::Owned({}({})) => {},",
+                    self.name,
+                    names.join(", "),
+                    construct("Owned")
+                );
+                let borrowed = format!(
+                    "::boow::Bow::Borrowed({}({})) => {},",
+                    self.name,
+                    names.join(", "),
+                    construct("Borrowed")
+                );
+                (definition, owned, borrowed)
+            }
+        };
+
+        format!(
+            "/// Projection of [`{name}`] generated by `#[derive(ProjectBow)]`.
+            {definition}
+
+            impl<{impl_generics}> ::boow::ProjectBow<{lifetime}> for {name}<{ty_generics}>
+            {impl_where_clause}
+            {{
+                type Projection = {projection}<{projection_generics}>;
+
+                fn project(bow: ::boow::Bow<{lifetime}, Self>) -> Self::Projection {{
+                    match bow {{
+                        {owned}
+                        {borrowed}
+                    }}
+                }}
+            }}",
+            name = self.name,
+            definition = definition,
+            impl_generics = impl_generics,
+            lifetime = LIFETIME,
+            ty_generics = ty_generics,
+            impl_where_clause = impl_where_clause,
+            projection = projection,
+            projection_generics = projection_generics,
+            owned = owned,
+            borrowed = borrowed,
+        )
+    }
+}
+
+impl Fields {
+    fn is_empty(&self) -> bool {
+        match *self {
+            Fields::Named(ref fields) | Fields::Unnamed(ref fields) => fields.is_empty(),
+        }
+    }
+}
+
+fn parse_fields(stream: TokenStream, named: bool) -> Result<Vec<Field>, String> {
+    let mut fields = Vec::new();
+    for field in split_top_level(stream.into_iter().collect()) {
+        let mut tokens = field.into_iter().peekable();
+        skip_attributes(&mut tokens);
+        let vis = parse_visibility(&mut tokens);
+        let name = if named {
+            let name = match tokens.next() {
+                Some(TokenTree::Ident(ident)) => ident.to_string(),
+                _ => return Err("expected field name".to_owned()),
+            };
+            if !is_punct(tokens.next().as_ref(), ':') {
+                return Err("expected `:` after field name".to_owned());
+            }
+            name
+        } else {
+            String::new()
+        };
+        let ty = tokens.collect::<TokenStream>().to_string();
+        fields.push(Field { vis, name, ty });
+    }
+    Ok(fields)
+}
+
+/// Split tokens on commas which are not nested in angle brackets, dropping
+/// empty trailing segments.
+fn split_top_level(tokens: Vec<TokenTree>) -> Vec<Vec<TokenTree>> {
+    let mut segments = Vec::new();
+    let mut current = Vec::new();
+    let mut depth = 0;
+    let mut prev_dash = false;
+    for token in tokens {
+        let mut dash = false;
+        if let TokenTree::Punct(ref p) = token {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !prev_dash => depth -= 1,
+                '-' => dash = p.spacing() == Spacing::Joint,
+                ',' if depth == 0 => {
+                    segments.push(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        prev_dash = dash;
+        current.push(token);
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+/// Strip the default of a generic parameter, turning e.g.
+/// `I: Iterator<Item = u8> = Empty<u8>` into `I: Iterator<Item = u8>`.
+fn without_default(param: &[TokenTree]) -> String {
+    let mut depth = 0;
+    let mut prev_dash = false;
+    let mut tokens = Vec::new();
+    for token in param {
+        let mut dash = false;
+        if let TokenTree::Punct(ref p) = *token {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !prev_dash => depth -= 1,
+                '-' => dash = p.spacing() == Spacing::Joint,
+                '=' if depth == 0 => break,
+                _ => {}
+            }
+        }
+        prev_dash = dash;
+        tokens.push(token.clone());
+    }
+    tokens.into_iter().collect::<TokenStream>().to_string()
+}
+
+/// Turn a generic parameter such as `T: Clone = u8` into the corresponding
+/// argument `T`.
+fn generic_argument(param: &[TokenTree]) -> String {
+    let mut tokens = param.iter();
+    match tokens.next() {
+        Some(TokenTree::Punct(ref p)) if p.as_char() == '\'' => {
+            format!(
+                "'{}",
+                tokens.next().map(|t| t.to_string()).unwrap_or_default()
+            )
+        }
+        Some(TokenTree::Ident(ref ident)) if ident.to_string() == "const" => {
+            tokens.next().map(|t| t.to_string()).unwrap_or_default()
+        }
+        Some(token) => token.to_string(),
+        None => String::new(),
+    }
+}
+
+fn skip_attributes<I>(tokens: &mut std::iter::Peekable<I>)
+where
+    I: Iterator<Item = TokenTree>,
+{
+    while is_punct(tokens.peek(), '#') {
+        tokens.next();
+        tokens.next();
+    }
+}
+
+fn parse_visibility<I>(tokens: &mut std::iter::Peekable<I>) -> String
+where
+    I: Iterator<Item = TokenTree>,
+{
+    let is_pub = match tokens.peek() {
+        Some(TokenTree::Ident(ref ident)) => ident.to_string() == "pub",
+        _ => false,
+    };
+    if !is_pub {
+        return String::new();
+    }
+    let mut vis = tokens.next().unwrap().to_string();
+    if let Some(TokenTree::Group(ref group)) = tokens.peek() {
+        if group.delimiter() == Delimiter::Parenthesis {
+            vis.push_str(&group.to_string());
+        }
+    }
+    if vis.len() > 3 {
+        tokens.next();
+    }
+    vis
+}
+
+fn is_punct(token: Option<&TokenTree>, c: char) -> bool {
+    match token {
+        Some(TokenTree::Punct(ref p)) => p.as_char() == c,
+        _ => false,
+    }
+}
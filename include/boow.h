@@ -0,0 +1,82 @@
+#ifndef BOOW_H
+#define BOOW_H
+
+/* Generated by cbindgen from src/capi.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Opaque handle to a borrowed or owned byte buffer.
+ */
+typedef struct BowBytes BowBytes;
+
+/**
+ * Create a handle owning a copy of `len` bytes starting at `data`.
+ *
+ * # Safety
+ *
+ * `data` must point to `len` readable bytes, or `len` must be zero.
+ */
+BowBytes *boow_bytes_owned(const uint8_t *data, size_t len);
+
+/**
+ * Create a handle borrowing `len` bytes starting at `data`.
+ *
+ * # Safety
+ *
+ * `data` must point to `len` readable bytes, or `len` must be zero. The
+ * bytes must stay alive and unmodified while the handle borrows them.
+ */
+BowBytes *boow_bytes_borrowed(const uint8_t *data, size_t len);
+
+/**
+ * Return whether the handle owns its bytes.
+ *
+ * # Safety
+ *
+ * `bytes` must be a live handle.
+ */
+bool boow_bytes_is_owned(const BowBytes *bytes);
+
+/**
+ * Return a pointer to the bytes of the handle.
+ *
+ * # Safety
+ *
+ * `bytes` must be a live handle.
+ */
+const uint8_t *boow_bytes_data(const BowBytes *bytes);
+
+/**
+ * Return the number of bytes of the handle.
+ *
+ * # Safety
+ *
+ * `bytes` must be a live handle.
+ */
+size_t boow_bytes_len(const BowBytes *bytes);
+
+/**
+ * Copy borrowed bytes into the handle, so that the borrowed buffer may be
+ * released. Owned handles are left untouched.
+ *
+ * # Safety
+ *
+ * `bytes` must be a live handle.
+ */
+void boow_bytes_make_owned(BowBytes *bytes);
+
+/**
+ * Release a handle. Does nothing if `bytes` is null.
+ *
+ * # Safety
+ *
+ * `bytes` must be null or a live handle, which must not be used afterwards.
+ */
+void boow_bytes_free(BowBytes *bytes);
+
+#endif /* BOOW_H */
@@ -0,0 +1,138 @@
+//! Compile-time checks pinning down the [`Send`] and [`Sync`] implementations
+//! of the types of this crate.
+//!
+//! None of these types implement the auto traits manually. They are derived
+//! from the fields, and the functions below fail to compile if a change to a
+//! type's representation alters them.
+//!
+//! A type holding either an owned `T` or a `&'a T` can be sent to another
+//! thread only if both `T: Send` (for the owned value) and `T: Sync` (for the
+//! reference) hold. Sharing it requires `T: Sync` only.
+#![allow(dead_code)]
+
+use typestate::{Borrowed, Owned, TypedBow};
+use {
+    Bow, BowDyn, BowMutSlice, BowOption, BowOrBox, CachedBow, GenBow, GuardBow, IgnoreAsciiCase,
+    Layered, OwnedItems, PinBow, SharedBow, SmallBow,
+};
+#[cfg(feature = "std")]
+use {BowCache, GlobalBow};
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+/// Compile only if `T` does not implement [`Sync`]: with `T: Sync`, both
+/// implementations apply and the call to `not_sync` becomes ambiguous.
+trait AmbiguousIfSync<A> {
+    fn not_sync() {}
+}
+
+impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+
+/// Compile only if `T` does not implement [`Send`], like [`AmbiguousIfSync`].
+trait AmbiguousIfSend<A> {
+    fn not_send() {}
+}
+
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+fn bow<'a, T: Send + Sync + 'a>() {
+    assert_send::<Bow<'a, T>>();
+    assert_sync::<Bow<'a, T>>();
+    assert_send::<BowOption<'a, T>>();
+    assert_sync::<BowOption<'a, T>>();
+    assert_send::<BowOrBox<'a, T>>();
+    assert_sync::<BowOrBox<'a, T>>();
+}
+
+fn bow_sync<'a, T: Sync + 'a>() {
+    assert_sync::<Bow<'a, T>>();
+    assert_sync::<BowOption<'a, T>>();
+    assert_sync::<BowOrBox<'a, T>>();
+}
+
+/// The inline storage of [`SmallBow`] is raw memory: whether it may cross
+/// threads is decided by `T` alone, through its `PhantomData<T>`.
+fn small_bow<'a, T: Send + Sync + 'a, const N: usize>() {
+    assert_send::<SmallBow<'a, T, N>>();
+    assert_sync::<SmallBow<'a, T, N>>();
+}
+
+fn gen_bow<'a, B: ?Sized + Sync + 'a, O: Send + Sync>() {
+    assert_send::<GenBow<'a, B, O>>();
+    assert_sync::<GenBow<'a, B, O>>();
+}
+
+fn bow_dyn<'a, F: ?Sized + Send + Sync + 'a>() {
+    assert_send::<BowDyn<'a, F>>();
+    assert_sync::<BowDyn<'a, F>>();
+}
+
+fn bow_mut_slice<'a, T: Send + Sync + 'a>() {
+    assert_send::<BowMutSlice<'a, T>>();
+    assert_sync::<BowMutSlice<'a, T>>();
+}
+
+fn pin_bow<'a, T: ?Sized + Send + Sync + 'a>() {
+    assert_send::<PinBow<'a, T>>();
+    assert_sync::<PinBow<'a, T>>();
+}
+
+fn shared_bow<'a, T: Send + Sync + 'a, P: Send + Sync>() {
+    assert_send::<SharedBow<'a, T, P>>();
+    assert_sync::<SharedBow<'a, T, P>>();
+}
+
+/// A [`Layered`] chain only holds the resolved layer, a [`BowOption`].
+fn layered<'a, T: Send + Sync + 'a>() {
+    assert_send::<Layered<'a, T>>();
+    assert_sync::<Layered<'a, T>>();
+}
+
+fn ignore_ascii_case<S: Send + Sync>() {
+    assert_send::<IgnoreAsciiCase<S>>();
+    assert_sync::<IgnoreAsciiCase<S>>();
+}
+
+fn owned_items<I: Send + Sync>() {
+    assert_send::<OwnedItems<I>>();
+    assert_sync::<OwnedItems<I>>();
+}
+
+/// Unlike a [`Bow`], a [`TypedBow`] holds either an owned value or a
+/// reference, so each state only requires the auto traits of its storage.
+fn typed_bow<'a, T: Send + 'a, U: Sync + 'a>() {
+    assert_send::<TypedBow<'a, T, Owned>>();
+    assert_send::<TypedBow<'a, U, Borrowed>>();
+    assert_sync::<TypedBow<'a, U, Borrowed>>();
+}
+
+/// The cache of [`CachedBow`] is a `OnceCell`, which may be sent but never
+/// shared across threads.
+fn cached_bow<'a, T: Send + Sync + 'a, U: Send + Sync, F: Send + Sync>() {
+    assert_send::<CachedBow<'a, T, U, F>>();
+    <CachedBow<'a, T, U, F> as AmbiguousIfSync<_>>::not_sync();
+}
+
+/// The guard of [`GuardBow`] is a boxed trait object without auto trait
+/// bounds, so that non-[`Send`] guards such as `MutexGuard` fit. Hence it is
+/// neither [`Send`] nor [`Sync`].
+fn guard_bow<'a, T: Send + Sync + 'a>() {
+    <GuardBow<'a, T> as AmbiguousIfSend<_>>::not_send();
+    <GuardBow<'a, T> as AmbiguousIfSync<_>>::not_sync();
+}
+
+/// A [`GlobalBow`] is meant to be a `static`, which requires [`Sync`].
+#[cfg(feature = "std")]
+fn global_bow<T: Send + Sync>() {
+    assert_send::<GlobalBow<T>>();
+    assert_sync::<GlobalBow<T>>();
+}
+
+#[cfg(feature = "std")]
+fn bow_cache<K: Send + Sync, V: Send + Sync, P: Send + Sync, S: Send + Sync>() {
+    assert_send::<BowCache<K, V, P, S>>();
+    assert_sync::<BowCache<K, V, P, S>>();
+}
@@ -0,0 +1,260 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use {Bow, MakeOwned};
+
+/// Read-through cache handing out [`Bow`]s: values already in the cache are
+/// borrowed, freshly computed ones are owned.
+///
+/// Which entries are evicted on insertion is decided by an
+/// [`EvictionPolicy`], by default [`NoEviction`].
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, BowCache, Fifo};
+///
+/// let mut cache = BowCache::with_policy(Fifo::new(2));
+/// let square = |n: &u64| n * n;
+///
+/// assert!(Bow::is_owned(&cache.get_or_insert_with(3, square)));
+/// assert!(Bow::is_borrowed(&cache.get_or_insert_with(3, square)));
+///
+/// cache.get_or_insert_with(4, square);
+/// cache.get_or_insert_with(5, square);
+/// assert!(cache.get_bow(&3).is_none());
+/// assert_eq!(cache.len(), 2);
+/// ```
+///
+/// [`Bow`]: crate::Bow
+pub struct BowCache<K, V, P = NoEviction, S = RandomState> {
+    map: HashMap<K, V, S>,
+    policy: P,
+}
+
+impl<K, V> BowCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Create an empty cache which never evicts entries.
+    pub fn new() -> Self {
+        BowCache::with_policy(NoEviction)
+    }
+}
+
+impl<K, V, P> BowCache<K, V, P>
+where
+    K: Hash + Eq,
+    P: EvictionPolicy<K>,
+{
+    /// Create an empty cache evicting entries according to `policy`.
+    pub fn with_policy(policy: P) -> Self {
+        BowCache {
+            map: HashMap::new(),
+            policy,
+        }
+    }
+}
+
+impl<K, V, S> BowCache<K, V, NoEviction, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Create an empty cache which never evicts entries, hashing keys with
+    /// `hash_builder`.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::BowCache;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let mut cache = BowCache::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    /// cache.insert("boow", 1);
+    /// assert_eq!(cache.get_bow("boow").as_deref(), Some(&1));
+    /// ```
+    pub fn with_hasher(hash_builder: S) -> Self {
+        BowCache::with_policy_and_hasher(NoEviction, hash_builder)
+    }
+}
+
+impl<K, V, P, S> BowCache<K, V, P, S>
+where
+    K: Hash + Eq,
+    P: EvictionPolicy<K>,
+    S: BuildHasher,
+{
+    /// Create an empty cache evicting entries according to `policy` and
+    /// hashing keys with `hash_builder`.
+    pub fn with_policy_and_hasher(policy: P, hash_builder: S) -> Self {
+        BowCache {
+            map: HashMap::with_hasher(hash_builder),
+            policy,
+        }
+    }
+
+    /// Borrow the cached value corresponding to the key, if any.
+    pub fn get_bow<Q>(&self, k: &Q) -> Option<Bow<'_, V>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.map.get(k).map(Bow::Borrowed)
+    }
+
+    /// Borrow the cached value corresponding to the key, or compute an owned
+    /// one with `f` without inserting it.
+    pub fn get_or_compute<Q, F>(&self, k: &Q, f: F) -> Bow<'_, V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+        F: FnOnce(&Q) -> V,
+    {
+        match self.map.get(k) {
+            Some(v) => Bow::Borrowed(v),
+            None => Bow::Owned(f(k)),
+        }
+    }
+
+    /// Borrow the cached value corresponding to the key, or compute an owned
+    /// one with `f` and insert a duplicate made with [`MakeOwned`].
+    pub fn get_or_insert_with<F>(&mut self, k: K, f: F) -> Bow<'_, V>
+    where
+        F: FnOnce(&K) -> V,
+        V: MakeOwned,
+    {
+        if self.map.contains_key(&k) {
+            return Bow::Borrowed(&self.map[&k]);
+        }
+        let v = f(&k);
+        self.insert(k, v.make_owned());
+        Bow::Owned(v)
+    }
+
+    /// Insert a value, evicting entries according to the policy. Return the
+    /// value previously cached for the key, if any.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(old) = self.map.get_mut(&k) {
+            return Some(mem::replace(old, v));
+        }
+        self.policy.inserted(&k);
+        self.map.insert(k, v);
+        while let Some(evicted) = self.policy.evict(self.map.len()) {
+            // Stop rather than loop forever on a key which is not cached, as
+            // the length would never shrink.
+            if self.map.remove(&evicted).is_none() {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Remove the value corresponding to the key.
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let (k, v) = self.map.remove_entry(k)?;
+        self.policy.removed(&k);
+        Some(v)
+    }
+
+    /// Return the number of cached values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Return whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<K, V> Default for BowCache<K, V>
+where
+    K: Hash + Eq,
+{
+    fn default() -> Self {
+        BowCache::new()
+    }
+}
+
+impl<K, V, P, S> fmt::Debug for BowCache<K, V, P, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
+/// Policy deciding which entries of a [`BowCache`] to evict.
+pub trait EvictionPolicy<K> {
+    /// Called before `key` is inserted into the cache.
+    fn inserted(&mut self, key: &K);
+
+    /// Called after `key` was removed from the cache with
+    /// [`BowCache::remove`].
+    fn removed(&mut self, key: &K);
+
+    /// Called after an insertion, with the number of entries in the cache,
+    /// until it returns [`None`]. Return the key of an entry to evict, if
+    /// any. Returning a key which is not cached stops the eviction.
+    fn evict(&mut self, len: usize) -> Option<K>;
+}
+
+/// Eviction policy keeping all entries.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoEviction;
+
+impl<K> EvictionPolicy<K> for NoEviction {
+    fn inserted(&mut self, _key: &K) {}
+
+    fn removed(&mut self, _key: &K) {}
+
+    fn evict(&mut self, _len: usize) -> Option<K> {
+        None
+    }
+}
+
+/// Eviction policy keeping at most `capacity` entries, evicting the oldest
+/// inserted ones first.
+#[derive(Debug, Clone)]
+pub struct Fifo<K> {
+    capacity: usize,
+    order: VecDeque<K>,
+}
+
+impl<K> Fifo<K> {
+    /// Create a policy keeping at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Fifo {
+            capacity,
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq> EvictionPolicy<K> for Fifo<K> {
+    fn inserted(&mut self, key: &K) {
+        self.order.push_back(key.clone());
+    }
+
+    fn removed(&mut self, key: &K) {
+        self.order.retain(|k| k != key);
+    }
+
+    fn evict(&mut self, len: usize) -> Option<K> {
+        if len > self.capacity {
+            self.order.pop_front()
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,276 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::any::Any;
+        use std::fmt;
+        use std::ops::Deref;
+    } else {
+        use alloc::boxed::Box;
+        use core::any::Any;
+        use core::fmt;
+        use core::ops::Deref;
+    }
+}
+
+use Bow;
+
+/// Borrowed-Or-oWned unsized value, typically a trait object.
+///
+/// Holds either a borrowed value or an owned boxed one. As [`BowDyn`]
+/// dereferences to the enclosed value, trait methods can be called directly.
+/// See [`BowFn`] and [`BowAny`] for the most common trait objects, and
+/// [`bow_dyn!`] to wrap other ones on stable Rust.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::BowDyn;
+/// use std::fmt::Display;
+///
+/// let name = "boow";
+/// let values: Vec<BowDyn<dyn Display>> = vec![BowDyn::Borrowed(&name), BowDyn::Owned(Box::new(2))];
+/// let rendered: Vec<_> = values.iter().map(|value| value.to_string()).collect();
+/// assert_eq!(rendered, ["boow", "2"]);
+/// ```
+///
+/// [`bow_dyn!`]: macro.bow_dyn.html
+pub enum BowDyn<'a, F: ?Sized + 'a> {
+    Owned(Box<F>),
+    Borrowed(&'a F),
+}
+
+/// Borrowed-Or-oWned callable.
+///
+/// Holds either a borrowed callable or an owned boxed one, typically a trait
+/// object such as `dyn Fn(Event)`. As [`BowFn`] dereferences to the callable,
+/// it can be called directly.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::BowFn;
+///
+/// let double = |x: i32| x * 2;
+/// let handlers: Vec<BowFn<dyn Fn(i32) -> i32>> = vec![
+///     BowFn::Borrowed(&double),
+///     BowFn::Owned(Box::new(|x| x + 1)),
+/// ];
+/// let results: Vec<_> = handlers.iter().map(|handler| handler(10)).collect();
+/// assert_eq!(results, [20, 11]);
+/// ```
+pub type BowFn<'a, F> = BowDyn<'a, F>;
+
+impl<'a, F: ?Sized + 'a> Deref for BowDyn<'a, F> {
+    type Target = F;
+    fn deref(&self) -> &F {
+        match *self {
+            BowDyn::Owned(ref f) => f,
+            BowDyn::Borrowed(f) => f,
+        }
+    }
+}
+
+impl<'a, F: ?Sized + 'a> BowDyn<'a, F> {
+    /// Get a mutable reference to the enclosed callable, e.g. to call a
+    /// `dyn FnMut`. Return [`None`] if the callable is not owned.
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut F> {
+        match *this {
+            BowDyn::Owned(ref mut f) => Some(f),
+            BowDyn::Borrowed(_) => None,
+        }
+    }
+
+    /// Consume the enclosed callable and return it if it is owned.
+    pub fn extract(this: Self) -> Option<Box<F>> {
+        match this {
+            BowDyn::Owned(f) => Some(f),
+            BowDyn::Borrowed(_) => None,
+        }
+    }
+}
+
+/// Borrowed-Or-oWned type-erased value, which can be downcast back to its
+/// concrete type.
+///
+/// Being a [`BowDyn`], it dereferences to `dyn Any`, so [`downcast_ref`] and
+/// [`is`] can be called directly.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, BowAny, BowDyn};
+///
+/// let default = 42_i32;
+/// let values: Vec<BowAny> = vec![BowDyn::Borrowed(&default), BowDyn::Owned(Box::new("boow"))];
+/// assert_eq!(values[0].downcast_ref::<i32>(), Some(&42));
+///
+/// let mut values = values.into_iter();
+/// let number = BowAny::downcast::<i32>(values.next().unwrap()).unwrap();
+/// assert!(Bow::is_borrowed(&number));
+/// let name = BowAny::downcast::<&str>(values.next().unwrap()).unwrap();
+/// assert_eq!(Bow::extract(name), Some("boow"));
+/// ```
+///
+/// [`downcast_ref`]: https://doc.rust-lang.org/std/any/trait.Any.html#method.downcast_ref
+/// [`is`]: https://doc.rust-lang.org/std/any/trait.Any.html#method.is
+pub type BowAny<'a> = BowDyn<'a, dyn Any>;
+
+macro_rules! impl_downcast {
+    ($($any:ty),*) => {
+        $(
+            impl<'a> BowDyn<'a, $any> {
+                /// Get a mutable reference to the enclosed value if it is owned
+                /// and of type `U`.
+                pub fn downcast_mut<U: Any>(this: &mut Self) -> Option<&mut U> {
+                    BowDyn::borrow_mut(this).and_then(|any| any.downcast_mut())
+                }
+
+                /// Convert into a [`Bow`] of the concrete type `U`, keeping the
+                /// variant. Return `self` if the enclosed value is not of type
+                /// `U`.
+                ///
+                /// [`Bow`]: crate::Bow
+                pub fn downcast<U: Any>(this: Self) -> Result<Bow<'a, U>, Self> {
+                    match this {
+                        BowDyn::Owned(any) => any
+                            .downcast()
+                            .map(|u| Bow::Owned(*u))
+                            .map_err(BowDyn::Owned),
+                        BowDyn::Borrowed(any) => any
+                            .downcast_ref()
+                            .map(Bow::Borrowed)
+                            .ok_or(BowDyn::Borrowed(any)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_downcast!(dyn Any, dyn Any + Send);
+
+impl<'a, F: ?Sized + 'a> From<&'a F> for BowDyn<'a, F> {
+    fn from(f: &'a F) -> Self {
+        BowDyn::Borrowed(f)
+    }
+}
+
+impl<'a, F: ?Sized + 'a> From<Box<F>> for BowDyn<'a, F> {
+    fn from(f: Box<F>) -> Self {
+        BowDyn::Owned(f)
+    }
+}
+
+impl<'a, F: ?Sized + 'a> AsRef<F> for BowDyn<'a, F> {
+    fn as_ref(&self) -> &F {
+        self
+    }
+}
+
+impl<'a, F: ?Sized + 'a> fmt::Debug for BowDyn<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BowDyn::Owned(_) => f.write_str("Owned(..)"),
+            BowDyn::Borrowed(_) => f.write_str("Borrowed(..)"),
+        }
+    }
+}
+
+/// Define a Borrowed-Or-oWned trait object type on stable Rust.
+///
+/// `bow_dyn!(pub struct RendererBow(dyn Renderer));` defines a
+/// `RendererBow<'a>` wrapping a [`BowDyn`]`<'a, dyn Renderer + 'a>`, with
+/// `owned` and `borrowed` constructors accepting any type implementing
+/// `Renderer`. As unsized coercions of custom types are not stable, these
+/// constructors do the coercion to the trait object themselves.
+///
+/// The defined type dereferences to the trait object and converts from and
+/// into the underlying [`BowDyn`].
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate boow;
+///
+/// trait Renderer {
+///     fn render(&self) -> String;
+/// }
+///
+/// struct Text(&'static str);
+///
+/// impl Renderer for Text {
+///     fn render(&self) -> String {
+///         self.0.to_owned()
+///     }
+/// }
+///
+/// bow_dyn!(struct RendererBow(dyn Renderer));
+///
+/// # fn main() {
+/// let title = Text("Title");
+/// let renderers = vec![RendererBow::borrowed(&title), RendererBow::owned(Text("Body"))];
+/// let rendered: Vec<_> = renderers.iter().map(|r| r.render()).collect();
+/// assert_eq!(rendered, ["Title", "Body"]);
+/// # }
+/// ```
+///
+/// Bounds using the `Fn` sugar, such as `dyn Fn(u8) -> u8`, are accepted
+/// alone. To combine them with other bounds, parenthesize them, e.g.
+/// `dyn (Fn(u8) -> u8) + Send`, as `Fn(u8) -> u8 + Send` would be parsed
+/// with `u8 + Send` as the return type. Higher-ranked bounds such as
+/// `for<'b> Fn(&'b str)` must be parenthesized too.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate boow;
+///
+/// bow_dyn!(struct Step(dyn Fn(u8) -> u8));
+/// bow_dyn!(struct SendStep(dyn (Fn(u8) -> u8) + Send));
+///
+/// # fn main() {
+/// let double = |x: u8| x * 2;
+/// assert_eq!(Step::borrowed(&double)(2), 4);
+/// assert_eq!(SendStep::owned(|x: u8| x + 1)(2), 3);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bow_dyn {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident(dyn $fn:ident($($arg:ty),* $(,)?) $(-> $ret:ty)?) $(;)?) => {
+        $crate::bow_dyn!($(#[$attr])* $vis struct $name(dyn ($fn($($arg),*) $(-> $ret)?)));
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident(dyn $($bound:tt)+) $(;)?) => {
+        $(#[$attr])*
+        $vis struct $name<'a>(pub $crate::BowDyn<'a, dyn $($bound)+ + 'a>);
+
+        impl<'a> $name<'a> {
+            /// Wrap an owned value, boxing it.
+            pub fn owned<T: $($bound)+ + 'a>(t: T) -> Self {
+                $name($crate::BowDyn::Owned($crate::__private::Box::new(t)))
+            }
+
+            /// Wrap a borrowed value.
+            pub fn borrowed<T: $($bound)+ + 'a>(t: &'a T) -> Self {
+                $name($crate::BowDyn::Borrowed(t))
+            }
+
+            /// Unwrap the underlying `BowDyn`.
+            pub fn into_inner(this: Self) -> $crate::BowDyn<'a, dyn $($bound)+ + 'a> {
+                this.0
+            }
+        }
+
+        impl<'a> $crate::__private::Deref for $name<'a> {
+            type Target = dyn $($bound)+ + 'a;
+            fn deref(&self) -> &Self::Target {
+                &*self.0
+            }
+        }
+
+        impl<'a> From<$crate::BowDyn<'a, dyn $($bound)+ + 'a>> for $name<'a> {
+            fn from(bow: $crate::BowDyn<'a, dyn $($bound)+ + 'a>) -> Self {
+                $name(bow)
+            }
+        }
+
+        impl<'a> $crate::__private::fmt::Debug for $name<'a> {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter) -> $crate::__private::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+    };
+}
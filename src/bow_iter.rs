@@ -0,0 +1,139 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use {Bow, MakeOwned};
+
+/// Extension methods for iterators over [`Bow`]s.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, BowIterExt};
+///
+/// let default = 0;
+/// let values = vec![Bow::Owned(1), Bow::Borrowed(&default), Bow::Owned(2)];
+/// assert_eq!(values.clone().into_iter().count_owned(), 2);
+///
+/// let (owned, borrowed) = values.into_iter().partition_ownership();
+/// assert_eq!(owned, [1, 2]);
+/// assert_eq!(borrowed, [&0]);
+/// ```
+///
+/// [`Bow`]: crate::Bow
+pub trait BowIterExt<'a, T: 'a>: Iterator<Item = Bow<'a, T>> + Sized {
+    /// Collect the owned values, or return [`None`] as soon as a borrowed
+    /// value is met.
+    fn try_collect_owned(self) -> Option<Vec<T>> {
+        self.map(Bow::extract).collect()
+    }
+
+    /// Split owned values from borrowed ones, keeping their order.
+    fn partition_ownership(self) -> (Vec<T>, Vec<&'a T>) {
+        let mut owned = Vec::new();
+        let mut borrowed = Vec::new();
+        for bow in self {
+            match bow {
+                Bow::Owned(t) => owned.push(t),
+                Bow::Borrowed(t) => borrowed.push(t),
+            }
+        }
+        (owned, borrowed)
+    }
+
+    /// Count the owned values.
+    fn count_owned(self) -> usize {
+        self.filter(Bow::is_owned).count()
+    }
+}
+
+impl<'a, T: 'a, I> BowIterExt<'a, T> for I where I: Iterator<Item = Bow<'a, T>> {}
+
+/// Iterator whose items may borrow from the iterator itself, e.g. from an
+/// internal buffer, or be owned when the buffer cannot be lent.
+///
+/// As items borrow from the iterator, a [`LendingBowIter`] cannot implement
+/// [`Iterator`]: each item must be dropped before the next one is requested.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, LendingBowIter};
+///
+/// /// Yield each line, lending an internal buffer unless the line is empty.
+/// struct Lines<'s> {
+///     lines: std::str::Lines<'s>,
+///     buffer: String,
+/// }
+///
+/// impl<'s> LendingBowIter for Lines<'s> {
+///     type Item = String;
+///
+///     fn next(&mut self) -> Option<Bow<'_, String>> {
+///         let line = self.lines.next()?;
+///         if line.is_empty() {
+///             return Some(Bow::Owned("<empty>".to_owned()));
+///         }
+///         self.buffer.clear();
+///         self.buffer.push_str(line);
+///         Some(Bow::Borrowed(&self.buffer))
+///     }
+/// }
+///
+/// let lines = Lines { lines: "a\n\nb".lines(), buffer: String::new() };
+/// let lines: Vec<String> = lines.owned_items().collect();
+/// assert_eq!(lines, ["a", "<empty>", "b"]);
+/// ```
+pub trait LendingBowIter {
+    /// The type of the values the items enclose.
+    type Item;
+
+    /// Advance the iterator and return the next item.
+    fn next(&mut self) -> Option<Bow<'_, Self::Item>>;
+
+    /// Call `f` on each item.
+    fn for_each<F>(mut self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Bow<Self::Item>),
+    {
+        while let Some(item) = self.next() {
+            f(item);
+        }
+    }
+
+    /// Turn into a regular [`Iterator`], making owned duplicates of borrowed
+    /// items with [`MakeOwned`].
+    fn owned_items(self) -> OwnedItems<Self>
+    where
+        Self: Sized,
+        Self::Item: MakeOwned,
+    {
+        OwnedItems { iter: self }
+    }
+}
+
+impl<I: LendingBowIter + ?Sized> LendingBowIter for &mut I {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Bow<'_, Self::Item>> {
+        (**self).next()
+    }
+}
+
+/// Iterator over the items of a [`LendingBowIter`] made owned.
+///
+/// This struct is created by [`LendingBowIter::owned_items`].
+#[derive(Debug, Clone)]
+pub struct OwnedItems<I> {
+    iter: I,
+}
+
+impl<I> Iterator for OwnedItems<I>
+where
+    I: LendingBowIter,
+    I::Item: MakeOwned,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next().map(Bow::into_owned)
+    }
+}
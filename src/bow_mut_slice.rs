@@ -0,0 +1,139 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::ops::{Deref, DerefMut};
+    } else {
+        use alloc::vec::Vec;
+        use core::ops::{Deref, DerefMut};
+    }
+}
+
+use GenBow;
+
+/// Mutably Borrowed-Or-oWned slice.
+///
+/// Holds either a mutable borrow of the caller's buffer or an owned [`Vec`].
+/// Both can be mutated in place, e.g. sorted, through [`DerefMut`].
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::BowMutSlice;
+///
+/// fn normalize(samples: &mut BowMutSlice<f32>) {
+///     let max = samples.iter().cloned().fold(0.0, f32::max);
+///     for sample in samples.iter_mut() {
+///         *sample /= max;
+///     }
+/// }
+///
+/// let mut buffer = [1.0, 4.0, 2.0];
+/// let mut borrowed = BowMutSlice::Borrowed(&mut buffer);
+/// normalize(&mut borrowed);
+/// borrowed.sort_by(|a, b| a.partial_cmp(b).unwrap());
+/// assert_eq!(buffer, [0.25, 0.5, 1.0]);
+///
+/// let mut owned = BowMutSlice::Owned(vec![2.0, 1.0]);
+/// normalize(&mut owned);
+/// assert_eq!(owned.into_vec(), [1.0, 0.5]);
+/// ```
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`DerefMut`]: https://doc.rust-lang.org/std/ops/trait.DerefMut.html
+pub enum BowMutSlice<'a, T: 'a> {
+    Owned(Vec<T>),
+    Borrowed(&'a mut [T]),
+}
+
+impl<'a, T: 'a> BowMutSlice<'a, T> {
+    /// Return whether the slice is owned.
+    pub fn is_owned(&self) -> bool {
+        matches!(*self, BowMutSlice::Owned(_))
+    }
+
+    /// Return whether the slice is borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(*self, BowMutSlice::Borrowed(_))
+    }
+
+    /// Get a mutable reference to the enclosed slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Consume the enclosed slice and return it as a [`Vec`], copying it if
+    /// it is borrowed.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn into_vec(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        match self {
+            BowMutSlice::Owned(v) => v,
+            BowMutSlice::Borrowed(s) => s.to_vec(),
+        }
+    }
+}
+
+impl<'a, T: 'a> Deref for BowMutSlice<'a, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        match *self {
+            BowMutSlice::Owned(ref v) => v,
+            BowMutSlice::Borrowed(ref s) => s,
+        }
+    }
+}
+
+impl<'a, T: 'a> DerefMut for BowMutSlice<'a, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        match *self {
+            BowMutSlice::Owned(ref mut v) => v,
+            BowMutSlice::Borrowed(ref mut s) => s,
+        }
+    }
+}
+
+impl<'a, T: 'a> From<Vec<T>> for BowMutSlice<'a, T> {
+    fn from(v: Vec<T>) -> Self {
+        BowMutSlice::Owned(v)
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut [T]> for BowMutSlice<'a, T> {
+    fn from(s: &'a mut [T]) -> Self {
+        BowMutSlice::Borrowed(s)
+    }
+}
+
+impl<'a, T: 'a> AsRef<[T]> for BowMutSlice<'a, T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<'a, T: 'a> AsMut<[T]> for BowMutSlice<'a, T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: 'a] BowMutSlice<'a, T> => [T]:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug
+}
+
+impl<'a, T: 'a> Default for BowMutSlice<'a, T> {
+    fn default() -> Self {
+        BowMutSlice::Owned(Vec::new())
+    }
+}
+
+impl<'a, T: 'a> From<BowMutSlice<'a, T>> for GenBow<'a, [T], Vec<T>> {
+    /// Give up mutable access to a borrowed slice.
+    fn from(slice: BowMutSlice<'a, T>) -> Self {
+        match slice {
+            BowMutSlice::Owned(v) => GenBow::Owned(v),
+            BowMutSlice::Borrowed(s) => GenBow::Borrowed(s),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::cmp::Ordering;
+        use std::fmt;
+        use std::hash::{Hash, Hasher};
+        use std::mem;
+    } else {
+        use core::cmp::Ordering;
+        use core::fmt;
+        use core::hash::{Hash, Hasher};
+        use core::mem;
+    }
+}
+
+use Bow;
+
+/// Optional Borrow-Or-oWned value.
+///
+/// Equivalent to `Option<Bow<'a, T>>`, but stored in a single enum so that
+/// it can be matched at once and does not pay for two discriminants.
+#[derive(Copy, Default)]
+pub enum BowOption<'a, T: 'a> {
+    #[default]
+    None,
+    Owned(T),
+    Borrowed(&'a T),
+}
+
+impl<'a, T: 'a> Clone for BowOption<'a, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            BowOption::None => BowOption::None,
+            BowOption::Owned(ref t) => BowOption::Owned(t.clone()),
+            BowOption::Borrowed(t) => BowOption::Borrowed(t),
+        }
+    }
+
+    /// Reuse the resources of an owned value, e.g. the capacity of a
+    /// `String`, if both values are owned.
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (BowOption::Owned(t), BowOption::Owned(s)) => t.clone_from(s),
+            (this, source) => *this = source.clone(),
+        }
+    }
+}
+
+impl<'a, T: 'a> BowOption<'a, T> {
+    /// Return whether there is a value.
+    pub fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+
+    /// Return whether there is no value.
+    pub fn is_none(&self) -> bool {
+        matches!(*self, BowOption::None)
+    }
+
+    /// Get a reference to the value, if any.
+    pub fn get(&self) -> Option<&T> {
+        match *self {
+            BowOption::None => None,
+            BowOption::Owned(ref t) => Some(t),
+            BowOption::Borrowed(t) => Some(t),
+        }
+    }
+
+    /// Take the value out, leaving [`None`] in its place.
+    ///
+    /// [`None`]: BowOption::None
+    pub fn take(&mut self) -> Self {
+        mem::replace(self, BowOption::None)
+    }
+
+    /// Return `self` if it holds a value, `other` otherwise.
+    pub fn or(self, other: BowOption<'a, T>) -> Self {
+        match self {
+            BowOption::None => other,
+            bow => bow,
+        }
+    }
+
+    /// Get the value, or borrow `default` if there is none.
+    pub fn or_borrowed(self, default: &'a T) -> Bow<'a, T> {
+        self.into_option().unwrap_or(Bow::Borrowed(default))
+    }
+
+    /// Convert into an `Option<Bow<'a, T>>`.
+    pub fn into_option(self) -> Option<Bow<'a, T>> {
+        match self {
+            BowOption::None => None,
+            BowOption::Owned(t) => Some(Bow::Owned(t)),
+            BowOption::Borrowed(t) => Some(Bow::Borrowed(t)),
+        }
+    }
+}
+
+impl<'a, T: 'a> From<Bow<'a, T>> for BowOption<'a, T> {
+    fn from(bow: Bow<'a, T>) -> Self {
+        match bow {
+            Bow::Owned(t) => BowOption::Owned(t),
+            Bow::Borrowed(t) => BowOption::Borrowed(t),
+        }
+    }
+}
+
+impl<'a, T: 'a> From<Option<Bow<'a, T>>> for BowOption<'a, T> {
+    fn from(bow: Option<Bow<'a, T>>) -> Self {
+        bow.map_or(BowOption::None, BowOption::from)
+    }
+}
+
+impl<'a, T: 'a> From<BowOption<'a, T>> for Option<Bow<'a, T>> {
+    fn from(bow: BowOption<'a, T>) -> Self {
+        bow.into_option()
+    }
+}
+
+impl<'a, T: 'a> Eq for BowOption<'a, T> where T: Eq {}
+
+impl<'a, T: 'a> Ord for BowOption<'a, T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &BowOption<'a, T>) -> Ordering {
+        Ord::cmp(&self.get(), &other.get())
+    }
+}
+
+impl<'a, T: 'a> PartialEq for BowOption<'a, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &BowOption<'a, T>) -> bool {
+        PartialEq::eq(&self.get(), &other.get())
+    }
+}
+
+impl<'a, T: 'a> PartialOrd for BowOption<'a, T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &BowOption<'a, T>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&self.get(), &other.get())
+    }
+}
+
+impl<'a, T: 'a> fmt::Debug for BowOption<'a, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<'a, T: 'a> Hash for BowOption<'a, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.get(), state)
+    }
+}
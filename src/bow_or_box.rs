@@ -0,0 +1,167 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        #[cfg(feature = "borrow")]
+        use std::borrow::Borrow;
+        use std::ops::Deref;
+    } else {
+        #[cfg(feature = "borrow")]
+        use alloc::borrow::Borrow;
+        use alloc::boxed::Box;
+        use core::ops::Deref;
+    }
+}
+
+use {Bow, MakeOwned};
+
+/// Borrow-Or-oWned smart pointer with an additional heap-allocated variant.
+///
+/// Like [`Bow`], but owned values can either be stored inline with
+/// [`Owned`] or on the heap with [`Boxed`]. Boxing large values avoids
+/// bloating the size of the whole enum.
+///
+/// [`Bow`]: crate::Bow
+/// [`Owned`]: BowOrBox::Owned
+/// [`Boxed`]: BowOrBox::Boxed
+pub enum BowOrBox<'a, T: 'a> {
+    Owned(T),
+    Borrowed(&'a T),
+    Boxed(Box<T>),
+}
+
+#[cfg(feature = "borrow")]
+impl<'a, T: 'a> Borrow<T> for BowOrBox<'a, T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<'a, T: 'a> Deref for BowOrBox<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match *self {
+            BowOrBox::Owned(ref t) => t,
+            BowOrBox::Borrowed(t) => t,
+            BowOrBox::Boxed(ref t) => t,
+        }
+    }
+}
+
+impl<'a, T: 'a> BowOrBox<'a, T> {
+    /// Get a mutable reference to the enclosed value. Return [`None`] if the
+    /// value is not owned, either inline or boxed.
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut T> {
+        match *this {
+            BowOrBox::Owned(ref mut t) => Some(t),
+            BowOrBox::Borrowed(_) => None,
+            BowOrBox::Boxed(ref mut t) => Some(t),
+        }
+    }
+
+    /// Consume the enclosed value and return it if it is owned, either inline
+    /// or boxed.
+    pub fn extract(this: Self) -> Option<T> {
+        match this {
+            BowOrBox::Owned(t) => Some(t),
+            BowOrBox::Borrowed(_) => None,
+            BowOrBox::Boxed(t) => Some(*t),
+        }
+    }
+
+    /// Consume the enclosed value and return it, making an owned duplicate
+    /// with [`MakeOwned`] if it is borrowed.
+    pub fn into_owned(this: Self) -> T
+    where
+        T: MakeOwned,
+    {
+        match this {
+            BowOrBox::Owned(t) => t,
+            BowOrBox::Borrowed(t) => t.make_owned(),
+            BowOrBox::Boxed(t) => *t,
+        }
+    }
+
+    /// Consume the enclosed value and return it boxed, making an owned
+    /// duplicate with [`MakeOwned`] if it is borrowed.
+    pub fn into_box(this: Self) -> Box<T>
+    where
+        T: MakeOwned,
+    {
+        match this {
+            BowOrBox::Owned(t) => Box::new(t),
+            BowOrBox::Borrowed(t) => Box::new(t.make_owned()),
+            BowOrBox::Boxed(t) => t,
+        }
+    }
+
+    /// Move an inline owned value to the heap. Other variants are returned
+    /// as is.
+    pub fn boxed(this: Self) -> Self {
+        match this {
+            BowOrBox::Owned(t) => BowOrBox::Boxed(Box::new(t)),
+            other => other,
+        }
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: 'a] BowOrBox<'a, T> => T:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+}
+
+impl<'a, T: 'a> Clone for BowOrBox<'a, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            BowOrBox::Owned(ref t) => BowOrBox::Owned(t.clone()),
+            BowOrBox::Borrowed(t) => BowOrBox::Borrowed(t),
+            BowOrBox::Boxed(ref t) => BowOrBox::Boxed(t.clone()),
+        }
+    }
+
+    /// Reuse the resources of an owned value, e.g. the capacity of a
+    /// `String`, if both values are owned.
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (BowOrBox::Owned(t), BowOrBox::Owned(s)) => t.clone_from(s),
+            (BowOrBox::Boxed(t), BowOrBox::Boxed(s)) => t.clone_from(s),
+            (this, source) => *this = source.clone(),
+        }
+    }
+}
+
+impl<'a, T: 'a> Default for BowOrBox<'a, T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        BowOrBox::Owned(T::default())
+    }
+}
+
+impl<'a, T: 'a> From<Box<T>> for BowOrBox<'a, T> {
+    fn from(t: Box<T>) -> Self {
+        BowOrBox::Boxed(t)
+    }
+}
+
+impl<'a, T: 'a> From<Bow<'a, T>> for BowOrBox<'a, T> {
+    fn from(bow: Bow<'a, T>) -> Self {
+        match bow {
+            Bow::Owned(t) => BowOrBox::Owned(t),
+            Bow::Borrowed(t) => BowOrBox::Borrowed(t),
+        }
+    }
+}
+
+impl<'a, T: 'a> From<BowOrBox<'a, T>> for Bow<'a, T> {
+    /// Move a boxed value out of its box into an owned [`Bow`].
+    fn from(bow: BowOrBox<'a, T>) -> Self {
+        match bow {
+            BowOrBox::Owned(t) => Bow::Owned(t),
+            BowOrBox::Borrowed(t) => Bow::Borrowed(t),
+            BowOrBox::Boxed(t) => Bow::Owned(*t),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::cell::OnceCell;
+        use std::fmt;
+    } else {
+        use core::cell::OnceCell;
+        use core::fmt;
+    }
+}
+
+use Bow;
+
+/// A [`Bow`] paired with a value derived from it, computed lazily on first
+/// access and then cached.
+///
+/// Like the [`OnceCell`] holding the cache, a [`CachedBow`] may be sent to
+/// another thread but is never [`Sync`].
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, CachedBow};
+///
+/// let source = "  Hello  ".to_owned();
+/// let cached = CachedBow::new(Bow::Borrowed(&source), |s: &String| s.trim().to_lowercase());
+/// assert_eq!(cached.source(), "  Hello  ");
+/// assert_eq!(cached.derived(), "hello");
+/// ```
+///
+/// [`OnceCell`]: https://doc.rust-lang.org/std/cell/struct.OnceCell.html
+pub struct CachedBow<'a, T: 'a, U, F = fn(&T) -> U> {
+    source: Bow<'a, T>,
+    derive: F,
+    cache: OnceCell<U>,
+}
+
+impl<'a, T: 'a, U, F> CachedBow<'a, T, U, F>
+where
+    F: Fn(&T) -> U,
+{
+    /// Pair `source` with the value computed by `derive` from it.
+    pub fn new(source: Bow<'a, T>, derive: F) -> Self {
+        CachedBow {
+            source,
+            derive,
+            cache: OnceCell::new(),
+        }
+    }
+
+    /// Get a reference to the source value.
+    pub fn source(&self) -> &T {
+        &self.source
+    }
+
+    /// Get a reference to the derived value, computing it if needed.
+    pub fn derived(&self) -> &U {
+        self.cache.get_or_init(|| (self.derive)(&self.source))
+    }
+
+    /// Get a reference to the derived value if it was already computed.
+    pub fn get_derived(&self) -> Option<&U> {
+        self.cache.get()
+    }
+
+    /// Replace the source value, discarding the cached derived value.
+    pub fn set_source(&mut self, source: Bow<'a, T>) {
+        self.source = source;
+        self.cache = OnceCell::new();
+    }
+
+    /// Return the source value and the derived value, if it was computed.
+    pub fn into_parts(self) -> (Bow<'a, T>, Option<U>) {
+        (self.source, self.cache.into_inner())
+    }
+}
+
+impl<'a, T: 'a, U, F> fmt::Debug for CachedBow<'a, T, U, F>
+where
+    T: fmt::Debug,
+    U: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CachedBow")
+            .field("source", &self.source)
+            .field("derived", &self.cache.get())
+            .finish()
+    }
+}
@@ -0,0 +1,87 @@
+//! Usages which must not compile, checked as `compile_fail` doc tests.
+//!
+//! A borrowed [`Bow`] cannot outlive the borrowed value:
+//!
+//! ```compile_fail,E0597
+//! extern crate boow;
+//! use boow::Bow;
+//!
+//! let bow: Bow<String>;
+//! {
+//!     let s = "short-lived".to_owned();
+//!     bow = Bow::Borrowed(&s);
+//! }
+//! println!("{}", *bow);
+//! ```
+//!
+//! Nor can a reference obtained with [`Bow::leak`]:
+//!
+//! ```compile_fail,E0597
+//! extern crate boow;
+//! use boow::Bow;
+//!
+//! let leaked: &String;
+//! {
+//!     let s = "short-lived".to_owned();
+//!     leaked = Bow::leak(Bow::Borrowed(&s));
+//! }
+//! println!("{}", leaked);
+//! ```
+//!
+//! [`Bow`] is covariant in `T`, but `T` keeps its own variance: a `Cell` of a
+//! short-lived reference cannot be passed off as a `Cell` of a `'static` one.
+//!
+//! ```compile_fail
+//! extern crate boow;
+//! use boow::Bow;
+//! use std::cell::Cell;
+//!
+//! fn extend<'a>(bow: Bow<'a, Cell<&'a str>>) -> Bow<'a, Cell<&'static str>> {
+//!     bow
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! A borrowed [`Bow`] cannot be sent to another thread if the borrowed value
+//! cannot be shared:
+//!
+//! ```compile_fail,E0277
+//! extern crate boow;
+//! use boow::Bow;
+//! use std::cell::Cell;
+//! use std::thread;
+//!
+//! let counter = Cell::new(0);
+//! let bow = Bow::Borrowed(&counter);
+//! thread::scope(|scope| {
+//!     scope.spawn(move || bow.get());
+//! });
+//! ```
+//!
+//! A [`GuardBow`] is never [`Send`], as its guard may not be:
+//!
+//! ```compile_fail,E0277
+//! extern crate boow;
+//! use boow::GuardBow;
+//! use std::thread;
+//!
+//! let bow = GuardBow::Owned(1);
+//! thread::spawn(move || *bow);
+//! ```
+//!
+//! Mutating an owned [`TypedBow`] is infallible, but a borrowed one cannot be
+//! mutated:
+//!
+//! ```compile_fail,E0596
+//! extern crate boow;
+//! use boow::typestate::TypedBow;
+//!
+//! let v = vec![1];
+//! let mut bow = TypedBow::borrowed(&v);
+//! bow.push(2);
+//! ```
+//!
+//! [`Bow`]: crate::Bow
+//! [`Bow::leak`]: crate::Bow::leak
+//! [`GuardBow`]: crate::GuardBow
+//! [`TypedBow`]: crate::typestate::TypedBow
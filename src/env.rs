@@ -0,0 +1,36 @@
+//! Environment variable lookups falling back to borrowed defaults.
+//!
+//! This is the typical "borrowed default or owned override" situation: the
+//! default is a `'static` borrow, and only values actually read from the
+//! environment are owned.
+//!
+//! ```rust
+//! extern crate boow;
+//! use boow::env;
+//!
+//! let level = env::var_or("BOOW_EXAMPLE_UNSET_LOG_LEVEL", "info");
+//! assert_eq!(&*level, "info");
+//! ```
+
+use std::env;
+use std::ffi::OsStr;
+
+use GenBow;
+
+/// Read the environment variable `key`, or borrow `default` if it is not set
+/// or not valid unicode.
+pub fn var_or<K: AsRef<OsStr>>(key: K, default: &'static str) -> GenBow<'static, str> {
+    match env::var(key) {
+        Ok(value) => GenBow::Owned(value),
+        Err(_) => GenBow::Borrowed(default),
+    }
+}
+
+/// Read the environment variable `key`, or borrow `default` if it is not
+/// set.
+pub fn var_os_or<K: AsRef<OsStr>>(key: K, default: &'static OsStr) -> GenBow<'static, OsStr> {
+    match env::var_os(key) {
+        Some(value) => GenBow::Owned(value),
+        None => GenBow::Borrowed(default),
+    }
+}
@@ -0,0 +1,1460 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::borrow::{Borrow, Cow, ToOwned};
+        use std::cmp::Ordering;
+        use std::convert::TryFrom;
+        use std::ffi::{OsStr, OsString};
+        use std::fmt;
+        use std::hash::{Hash, Hasher};
+        use std::io;
+        use std::iter::FromIterator;
+        use std::ops::{Add, AddAssign, Deref, Index, Range};
+        use std::path::{self, Component, Path, PathBuf, StripPrefixError};
+        use std::slice::IterMut;
+        use std::str::{self, Utf8Error};
+        use std::vec;
+    } else {
+        use alloc::borrow::{Borrow, Cow, ToOwned};
+        use alloc::string::String;
+        use alloc::vec::{self, Vec};
+        use core::cmp::Ordering;
+        use core::convert::TryFrom;
+        use core::fmt;
+        use core::hash::{Hash, Hasher};
+        use core::iter::FromIterator;
+        use core::ops::{Add, AddAssign, Deref, Index, Range};
+        use core::slice::IterMut;
+        use core::str::{self, Utf8Error};
+    }
+}
+
+/// Borrow-Or-oWned smart pointer whose owned variant may have a different
+/// type than the borrowed one.
+///
+/// By default, the owned type is `B`'s [`ToOwned::Owned`] type, which makes
+/// [`GenBow`] a drop-in superset of [`Cow`]: `GenBow<'a, str>` holds either a
+/// `&'a str` or a `String`. Unlike [`Cow`], any owned type implementing
+/// [`Borrow<B>`] can be used, and [`ToOwned`] is only required to convert a
+/// borrowed value into an owned one.
+///
+/// The compiler stores the variant in a niche of the owned type when it has
+/// one, e.g. the capacity of a `String`, so that `GenBow<str, String>`,
+/// `GenBow<[T], Vec<T>>` and `GenBow<Path, PathBuf>` take three words, like
+/// the owned type alone.
+///
+/// Like for [`Bow`], functions working on any `B`, such as [`to_mut`], are
+/// associated functions. Functions specific to `str`, `[T]` or [`Path`] are
+/// methods, unless they would clash with a method of the target (e.g.
+/// [`get_mut`]).
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GenBow;
+///
+/// let mut name: GenBow<str> = GenBow::Borrowed("boow");
+/// GenBow::to_mut(&mut name).push_str("!");
+/// assert_eq!(&*name, "boow!");
+/// ```
+///
+/// [`Bow`]: crate::Bow
+/// [`to_mut`]: GenBow::to_mut
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+/// [`get_mut`]: GenBow::get_mut
+/// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+pub enum GenBow<'a, B: ?Sized + 'a, O = <B as ToOwned>::Owned> {
+    Owned(O),
+    Borrowed(&'a B),
+}
+
+impl<'a, B: ?Sized + 'a, O> Borrow<B> for GenBow<'a, B, O>
+where
+    O: Borrow<B>,
+{
+    fn borrow(&self) -> &B {
+        match *self {
+            GenBow::Owned(ref o) => o.borrow(),
+            GenBow::Borrowed(b) => b,
+        }
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> Deref for GenBow<'a, B, O>
+where
+    O: Borrow<B>,
+{
+    type Target = B;
+    fn deref(&self) -> &B {
+        self.borrow()
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> GenBow<'a, B, O> {
+    /// Get a mutable reference to the enclosed owned value. Return [`None`] if
+    /// the value is not owned.
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut O> {
+        match *this {
+            GenBow::Owned(ref mut o) => Some(o),
+            GenBow::Borrowed(_) => None,
+        }
+    }
+
+    /// Consume the enclosed value and return it if it is owned.
+    pub fn extract(this: Self) -> Option<O> {
+        match this {
+            GenBow::Owned(o) => Some(o),
+            GenBow::Borrowed(_) => None,
+        }
+    }
+
+    /// Consume the enclosed value and return it, converting it with
+    /// [`ToOwned`] if it is borrowed.
+    pub fn into_owned(this: Self) -> O
+    where
+        B: ToOwned<Owned = O>,
+    {
+        match this {
+            GenBow::Owned(o) => o,
+            GenBow::Borrowed(b) => b.to_owned(),
+        }
+    }
+
+    /// Get a mutable reference to the enclosed owned value, converting it with
+    /// [`ToOwned`] first if it is borrowed.
+    pub fn to_mut(this: &mut Self) -> &mut O
+    where
+        B: ToOwned<Owned = O>,
+    {
+        if let GenBow::Borrowed(b) = *this {
+            *this = GenBow::Owned(b.to_owned());
+        }
+        match *this {
+            GenBow::Owned(ref mut o) => o,
+            GenBow::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl<'a> GenBow<'a, str, String> {
+    /// Borrowed empty string.
+    ///
+    /// Unlike [`Default::default`], which gives an owned empty [`String`],
+    /// [`EMPTY`] can be used in `const` contexts and stays borrowed.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// const NAME: GenBow<'static, str, String> = GenBow::<str, String>::EMPTY;
+    /// assert!(NAME.is_empty());
+    /// assert!(GenBow::extract(NAME).is_none());
+    /// ```
+    ///
+    /// [`EMPTY`]: GenBow::EMPTY
+    pub const EMPTY: Self = GenBow::Borrowed("");
+
+    /// Create an empty owned string with at least the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        GenBow::Owned(String::with_capacity(capacity))
+    }
+
+    /// Return the capacity of an owned string, or the length of a borrowed
+    /// one.
+    pub fn capacity(&self) -> usize {
+        match *self {
+            GenBow::Owned(ref s) => s.capacity(),
+            GenBow::Borrowed(s) => s.len(),
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more bytes, copying a
+    /// borrowed string into an owned one with that spare capacity.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut greeting: GenBow<str, String> = GenBow::Borrowed("Hello");
+    /// greeting.reserve(8);
+    /// assert!(greeting.capacity() >= 13);
+    /// GenBow::to_mut(&mut greeting).push_str(", boow!");
+    /// assert_eq!(greeting, "Hello, boow!");
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        match *self {
+            GenBow::Owned(ref mut s) => s.reserve(additional),
+            GenBow::Borrowed(b) => {
+                let mut s = String::with_capacity(b.len() + additional);
+                s.push_str(b);
+                *self = GenBow::Owned(s);
+            }
+        }
+    }
+
+    /// Convert bytes into a string, keeping borrowed bytes borrowed. Fail if
+    /// the bytes are not valid UTF-8.
+    pub fn from_utf8(bytes: GenBow<'a, [u8], Vec<u8>>) -> Result<Self, Utf8Error> {
+        match bytes {
+            GenBow::Owned(v) => String::from_utf8(v)
+                .map(GenBow::Owned)
+                .map_err(|e| e.utf8_error()),
+            GenBow::Borrowed(b) => str::from_utf8(b).map(GenBow::Borrowed),
+        }
+    }
+
+    /// Convert bytes into a string, replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`. Borrowed bytes stay borrowed unless a
+    /// replacement is needed.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let valid = GenBow::from_utf8_lossy(GenBow::Borrowed(b"boow"));
+    /// assert!(GenBow::extract(valid).is_none());
+    ///
+    /// let invalid = GenBow::from_utf8_lossy(GenBow::Borrowed(b"bo\xffow"));
+    /// assert_eq!(invalid, "bo\u{fffd}ow");
+    /// ```
+    pub fn from_utf8_lossy(bytes: GenBow<'a, [u8], Vec<u8>>) -> Self {
+        match bytes {
+            GenBow::Owned(v) => match String::from_utf8(v) {
+                Ok(s) => GenBow::Owned(s),
+                Err(e) => GenBow::Owned(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+            },
+            GenBow::Borrowed(b) => String::from_utf8_lossy(b).into(),
+        }
+    }
+
+    /// Consume the enclosed string and return it, copying it if it is
+    /// borrowed.
+    pub fn into_string(self) -> String {
+        GenBow::into_owned(self)
+    }
+
+    /// Append `s`, copying a borrowed string into an owned one first unless
+    /// `s` is empty.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut name: GenBow<str, String> = GenBow::Borrowed("boow");
+    /// name.push_str("");
+    /// assert!(GenBow::extract(name.clone()).is_none());
+    /// name.push_str("!");
+    /// assert_eq!(GenBow::extract(name), Some("boow!".to_owned()));
+    /// ```
+    pub fn push_str(&mut self, s: &str) {
+        if !s.is_empty() {
+            GenBow::to_mut(self).push_str(s);
+        }
+    }
+
+    /// Insert `s` at byte index `idx`, copying a borrowed string into an
+    /// owned one first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not on a `char` boundary.
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        GenBow::to_mut(self).insert_str(idx, s);
+    }
+
+    /// Shorten the string to `new_len` bytes. A borrowed string is re-sliced
+    /// and stays borrowed. Do nothing if `new_len` is greater than the
+    /// length of the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not on a `char` boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        match *self {
+            GenBow::Owned(ref mut s) => s.truncate(new_len),
+            GenBow::Borrowed(b) => {
+                if new_len < b.len() {
+                    *self = GenBow::Borrowed(&b[..new_len]);
+                }
+            }
+        }
+    }
+
+    /// Convert the string to ASCII lower case in place, copying a borrowed
+    /// string into an owned one first only if it has ASCII upper case
+    /// letters.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut name: GenBow<str, String> = GenBow::Borrowed("boow.rs");
+    /// name.truncate(4);
+    /// GenBow::make_ascii_lowercase(&mut name);
+    /// assert_eq!(GenBow::extract(name.clone()), None);
+    /// GenBow::make_ascii_uppercase(&mut name);
+    /// assert_eq!(GenBow::extract(name), Some("BOOW".to_owned()));
+    /// ```
+    pub fn make_ascii_lowercase(this: &mut Self) {
+        if this.bytes().any(|b| b.is_ascii_uppercase()) {
+            GenBow::to_mut(this).make_ascii_lowercase();
+        }
+    }
+
+    /// Convert the string to ASCII upper case in place, copying a borrowed
+    /// string into an owned one first only if it has ASCII lower case
+    /// letters.
+    pub fn make_ascii_uppercase(this: &mut Self) {
+        if this.bytes().any(|b| b.is_ascii_lowercase()) {
+            GenBow::to_mut(this).make_ascii_uppercase();
+        }
+    }
+
+    /// Remove leading and trailing whitespace. A borrowed string is re-sliced
+    /// and stays borrowed, and an owned string is only copied if it changes.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let name: GenBow<str, String> = GenBow::Borrowed("  boow\n");
+    /// assert_eq!(name.trimmed(), GenBow::Borrowed("boow"));
+    /// ```
+    pub fn trimmed(self) -> Self {
+        match self {
+            GenBow::Borrowed(b) => GenBow::Borrowed(b.trim()),
+            GenBow::Owned(s) => {
+                if s.trim().len() == s.len() {
+                    GenBow::Owned(s)
+                } else {
+                    GenBow::Owned(s.trim().to_owned())
+                }
+            }
+        }
+    }
+
+    /// Convert the string to lower case, copying it only if some character
+    /// changes.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let lower: GenBow<str, String> = GenBow::Borrowed("boow");
+    /// assert!(GenBow::extract(lower.lowercased()).is_none());
+    ///
+    /// let upper: GenBow<str, String> = GenBow::Borrowed("BOOW");
+    /// assert_eq!(GenBow::extract(upper.lowercased()), Some("boow".to_owned()));
+    /// ```
+    pub fn lowercased(self) -> Self {
+        let unchanged = self.chars().all(|c| {
+            let mut lower = c.to_lowercase();
+            lower.next() == Some(c) && lower.next().is_none()
+        });
+        if unchanged {
+            self
+        } else {
+            GenBow::Owned(self.to_lowercase())
+        }
+    }
+
+    /// Remove leading and trailing whitespace and replace every inner run of
+    /// whitespace with a single space. The string is only copied if an inner
+    /// run is not already a single space.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let normal: GenBow<str, String> = GenBow::Borrowed(" Hello, boow! ");
+    /// assert_eq!(normal.normalized_whitespace(), GenBow::Borrowed("Hello, boow!"));
+    ///
+    /// let spaced: GenBow<str, String> = GenBow::Borrowed("Hello,\t\tboow!");
+    /// let normalized = spaced.normalized_whitespace();
+    /// assert_eq!(GenBow::extract(normalized), Some("Hello, boow!".to_owned()));
+    /// ```
+    pub fn normalized_whitespace(self) -> Self {
+        let trimmed = self.trimmed();
+        let mut prev_whitespace = false;
+        let unchanged = trimmed.chars().all(|c| {
+            let whitespace = c.is_whitespace();
+            let normal = !whitespace || (c == ' ' && !prev_whitespace);
+            prev_whitespace = whitespace;
+            normal
+        });
+        if unchanged {
+            trimmed
+        } else {
+            GenBow::Owned(trimmed.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    /// Split the string by `separator` into segments which borrow from the
+    /// same data if the string is borrowed, or are owned otherwise.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let csv: GenBow<str, String> = GenBow::Borrowed("a,b");
+    /// let fields: Vec<_> = GenBow::split(csv, ",").collect();
+    /// assert_eq!(fields, [GenBow::Borrowed("a"), GenBow::Borrowed("b")]);
+    ///
+    /// let csv: GenBow<str, String> = GenBow::Owned("a,b".to_owned());
+    /// let fields: Vec<_> = GenBow::split(csv, ",").filter_map(GenBow::extract).collect();
+    /// assert_eq!(fields, ["a", "b"]);
+    /// ```
+    pub fn split(this: Self, separator: &str) -> Segments<'a> {
+        let ranges = segment_ranges(&this, this.split(separator));
+        Segments::new(this, ranges)
+    }
+
+    /// Split the string into lines, as [`str::lines`] does, into segments
+    /// which borrow from the same data if the string is borrowed, or are
+    /// owned otherwise.
+    ///
+    /// [`str::lines`]: https://doc.rust-lang.org/std/primitive.str.html#method.lines
+    pub fn lines(this: Self) -> Segments<'a> {
+        let ranges = segment_ranges(&this, this.lines());
+        Segments::new(this, ranges)
+    }
+
+    /// Split the string by whitespace, as [`str::split_whitespace`] does,
+    /// into segments which borrow from the same data if the string is
+    /// borrowed, or are owned otherwise.
+    ///
+    /// [`str::split_whitespace`]: https://doc.rust-lang.org/std/primitive.str.html#method.split_whitespace
+    pub fn split_whitespace(this: Self) -> Segments<'a> {
+        let ranges = segment_ranges(&this, this.split_whitespace());
+        Segments::new(this, ranges)
+    }
+}
+
+/// Byte ranges of `segments`, which must all be slices of `s`.
+fn segment_ranges<'s, I>(s: &'s str, segments: I) -> Vec<Range<usize>>
+where
+    I: Iterator<Item = &'s str>,
+{
+    let base = s.as_ptr() as usize;
+    segments
+        .map(|segment| {
+            let start = segment.as_ptr() as usize - base;
+            start..start + segment.len()
+        })
+        .collect()
+}
+
+/// Iterator over the segments of a [`GenBow`] string or slice.
+///
+/// Segments of a borrowed value are borrowed from the same data, while
+/// segments of an owned value are owned copies.
+///
+/// This struct is created by [`GenBow::split`], [`GenBow::lines`],
+/// [`GenBow::split_whitespace`], [`GenBow::chunks`] and [`GenBow::windows`].
+pub struct Segments<'a, B: ?Sized + ToOwned + 'a = str> {
+    source: GenBow<'a, B>,
+    ranges: vec::IntoIter<Range<usize>>,
+}
+
+impl<'a, B: ?Sized + ToOwned + 'a> Segments<'a, B> {
+    fn new(source: GenBow<'a, B>, ranges: Vec<Range<usize>>) -> Self {
+        Segments {
+            source,
+            ranges: ranges.into_iter(),
+        }
+    }
+}
+
+impl<'a, B> Iterator for Segments<'a, B>
+where
+    B: ?Sized + ToOwned + Index<Range<usize>, Output = B> + 'a,
+{
+    type Item = GenBow<'a, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.ranges.next()?;
+        Some(match self.source {
+            GenBow::Owned(ref o) => GenBow::Owned(Borrow::<B>::borrow(o)[range].to_owned()),
+            GenBow::Borrowed(b) => GenBow::Borrowed(&b[range]),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+}
+
+impl<'a, B> ExactSizeIterator for Segments<'a, B> where
+    B: ?Sized + ToOwned + Index<Range<usize>, Output = B> + 'a
+{
+}
+
+impl<'a, B: ?Sized + ToOwned + 'a> Clone for Segments<'a, B>
+where
+    B::Owned: Clone,
+{
+    fn clone(&self) -> Self {
+        Segments {
+            source: self.source.clone(),
+            ranges: self.ranges.clone(),
+        }
+    }
+}
+
+impl<'a, B: ?Sized + ToOwned + 'a> fmt::Debug for Segments<'a, B>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Segments")
+            .field("source", &self.source)
+            .field("ranges", &self.ranges)
+            .finish()
+    }
+}
+
+impl<'a, T: 'a> GenBow<'a, [T], Vec<T>> {
+    /// Borrowed empty slice.
+    ///
+    /// Unlike [`Default::default`], which gives an owned empty [`Vec`],
+    /// [`EMPTY`] can be used in `const` contexts and stays borrowed.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    /// [`EMPTY`]: GenBow::EMPTY
+    pub const EMPTY: Self = GenBow::Borrowed(&[]);
+
+    /// Create an empty owned [`Vec`] with at least the given capacity.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn with_capacity(capacity: usize) -> Self {
+        GenBow::Owned(Vec::with_capacity(capacity))
+    }
+
+    /// Return the capacity of an owned [`Vec`], or the length of a borrowed
+    /// slice.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn capacity(&self) -> usize {
+        match *self {
+            GenBow::Owned(ref v) => v.capacity(),
+            GenBow::Borrowed(s) => s.len(),
+        }
+    }
+
+    /// Get a mutable reference to an element of the slice. Return [`None`]
+    /// if the slice is not owned or `index` is out of bounds.
+    ///
+    /// Use [`to_mut`] to make a borrowed slice owned first.
+    ///
+    /// [`to_mut`]: GenBow::to_mut
+    pub fn get_mut(this: &mut Self, index: usize) -> Option<&mut T> {
+        GenBow::borrow_mut(this).and_then(|v| v.get_mut(index))
+    }
+
+    /// Iterate mutably over the elements of the slice. Return [`None`] if the
+    /// slice is not owned.
+    ///
+    /// Use [`to_mut`] to make a borrowed slice owned first.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut values: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2]);
+    /// assert!(GenBow::iter_mut(&mut values).is_none());
+    /// for value in GenBow::to_mut(&mut values).iter_mut() {
+    ///     *value *= 10;
+    /// }
+    /// assert_eq!(values, [10, 20][..]);
+    /// ```
+    ///
+    /// [`to_mut`]: GenBow::to_mut
+    pub fn iter_mut(this: &mut Self) -> Option<IterMut<'_, T>> {
+        GenBow::borrow_mut(this).map(|v| v.iter_mut())
+    }
+
+    /// Split the slice in two at `mid`. A borrowed slice is re-sliced into
+    /// two borrowed halves, while an owned [`Vec`] is split with
+    /// [`Vec::split_off`] into two owned halves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let values: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2, 3]);
+    /// let (head, tail) = GenBow::split_at(values, 1);
+    /// assert_eq!(head, GenBow::Borrowed(&[1][..]));
+    /// assert_eq!(tail, GenBow::Borrowed(&[2, 3][..]));
+    /// ```
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    /// [`Vec::split_off`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.split_off
+    pub fn split_at(this: Self, mid: usize) -> (Self, Self) {
+        match this {
+            GenBow::Owned(mut v) => {
+                let tail = v.split_off(mid);
+                (GenBow::Owned(v), GenBow::Owned(tail))
+            }
+            GenBow::Borrowed(b) => {
+                let (head, tail) = b.split_at(mid);
+                (GenBow::Borrowed(head), GenBow::Borrowed(tail))
+            }
+        }
+    }
+
+    /// Split the slice in two at `at`, keeping the head and returning the
+    /// tail. Both halves stay borrowed if the slice is borrowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the slice.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        match *self {
+            GenBow::Owned(ref mut v) => GenBow::Owned(v.split_off(at)),
+            GenBow::Borrowed(b) => {
+                let (head, tail) = b.split_at(at);
+                *self = GenBow::Borrowed(head);
+                GenBow::Borrowed(tail)
+            }
+        }
+    }
+}
+
+impl<'a, T: Clone> GenBow<'a, [T], Vec<T>> {
+    /// Reserve capacity for at least `additional` more elements, copying a
+    /// borrowed slice into an owned [`Vec`] with that spare capacity.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn reserve(&mut self, additional: usize) {
+        match *self {
+            GenBow::Owned(ref mut v) => v.reserve(additional),
+            GenBow::Borrowed(b) => {
+                let mut v = Vec::with_capacity(b.len() + additional);
+                v.extend_from_slice(b);
+                *self = GenBow::Owned(v);
+            }
+        }
+    }
+
+    /// Append `value`, copying a borrowed slice into an owned [`Vec`] first.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut values: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2]);
+    /// values.extend_from_slice(&[]);
+    /// assert!(GenBow::extract(values.clone()).is_none());
+    /// values.push(3);
+    /// values.insert(0, 0);
+    /// assert_eq!(GenBow::extract(values), Some(vec![0, 1, 2, 3]));
+    /// ```
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        GenBow::to_mut(self).push(value);
+    }
+
+    /// Append the elements of `other`, copying a borrowed slice into an owned
+    /// [`Vec`] first unless `other` is empty.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        if !other.is_empty() {
+            self.reserve(other.len());
+            GenBow::to_mut(self).extend_from_slice(other);
+        }
+    }
+
+    /// Insert `element` at `index`, copying a borrowed slice into an owned
+    /// [`Vec`] first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the slice.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.reserve(1);
+        GenBow::to_mut(self).insert(index, element);
+    }
+
+    /// Remove and return the element at `index`. Removing the first or last
+    /// element of a borrowed slice re-slices it and clones the element,
+    /// while removing any other element copies the slice into an owned
+    /// [`Vec`] first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut values: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2, 3, 4]);
+    /// assert_eq!(values.remove(3), 4);
+    /// assert_eq!(values.remove(0), 1);
+    /// assert_eq!(values, GenBow::Borrowed(&[2, 3][..]));
+    /// ```
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn remove(&mut self, index: usize) -> T {
+        match *self {
+            GenBow::Borrowed(b) if index == 0 && !b.is_empty() => {
+                *self = GenBow::Borrowed(&b[1..]);
+                b[0].clone()
+            }
+            GenBow::Borrowed(b) if index + 1 == b.len() => {
+                *self = GenBow::Borrowed(&b[..index]);
+                b[index].clone()
+            }
+            _ => GenBow::to_mut(self).remove(index),
+        }
+    }
+
+    /// Consume the enclosed slice and return it as a [`Vec`], copying it if
+    /// it is borrowed.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn into_vec(self) -> Vec<T> {
+        GenBow::into_owned(self)
+    }
+
+    /// Concatenate the slices. A single slice is kept as is, so that a single
+    /// borrowed slice stays borrowed, and no slice gives [`EMPTY`].
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let chunk: GenBow<[u8], Vec<u8>> = GenBow::Borrowed(b"boow");
+    /// assert!(GenBow::extract(GenBow::concat(vec![chunk.clone()])).is_none());
+    ///
+    /// let chunks = vec![chunk, GenBow::Borrowed(b"!")];
+    /// assert_eq!(GenBow::extract(GenBow::concat(chunks)), Some(b"boow!".to_vec()));
+    /// ```
+    ///
+    /// [`EMPTY`]: GenBow::EMPTY
+    pub fn concat<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        GenBow::join(parts, &[])
+    }
+
+    /// Concatenate the slices, placing `separator` between each of them. A
+    /// single slice is kept as is, so that a single borrowed slice stays
+    /// borrowed, and no slice gives [`EMPTY`]. With an empty `separator`,
+    /// empty slices are skipped and a borrowed empty first slice is replaced
+    /// by the next one, as `+=` does for strings, so that a single non-empty
+    /// borrowed slice stays borrowed.
+    ///
+    /// [`EMPTY`]: GenBow::EMPTY
+    pub fn join<I>(parts: I, separator: &[T]) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut parts = parts.into_iter();
+        let mut first = match parts.next() {
+            Some(first) => first,
+            None => return Self::EMPTY,
+        };
+        for part in parts {
+            if separator.is_empty() {
+                if part.is_empty() {
+                    continue;
+                }
+                if let GenBow::Borrowed(&[]) = first {
+                    first = part;
+                    continue;
+                }
+            }
+            first.reserve(separator.len() + part.len());
+            first.extend_from_slice(separator);
+            first.extend_from_slice(&part);
+        }
+        first
+    }
+
+    /// Split the slice into chunks of `size` elements, the last one being
+    /// shorter if `size` does not divide the length, as [`slice::chunks`]
+    /// does. Chunks borrow from the same data if the slice is borrowed, or
+    /// are owned copies otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let values: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2, 3]);
+    /// let chunks: Vec<_> = GenBow::chunks(values, 2).collect();
+    /// assert_eq!(chunks, [GenBow::Borrowed(&[1, 2][..]), GenBow::Borrowed(&[3][..])]);
+    ///
+    /// let values: GenBow<[i32], Vec<i32>> = GenBow::Owned(vec![1, 2, 3]);
+    /// let chunks: Vec<_> = GenBow::chunks(values, 2).filter_map(GenBow::extract).collect();
+    /// assert_eq!(chunks, [vec![1, 2], vec![3]]);
+    /// ```
+    ///
+    /// [`slice::chunks`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunks
+    pub fn chunks(this: Self, size: usize) -> Segments<'a, [T]> {
+        assert!(size != 0, "chunk size must be non-zero");
+        let len = this.len();
+        let ranges = (0..len)
+            .step_by(size)
+            .map(|start| start..len.min(start + size))
+            .collect();
+        Segments::new(this, ranges)
+    }
+
+    /// Iterate over all contiguous windows of `size` elements, as
+    /// [`slice::windows`] does. Windows borrow from the same data if the
+    /// slice is borrowed, or are owned copies otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// [`slice::windows`]: https://doc.rust-lang.org/std/primitive.slice.html#method.windows
+    pub fn windows(this: Self, size: usize) -> Segments<'a, [T]> {
+        assert!(size != 0, "window size must be non-zero");
+        let count = (this.len() + 1).saturating_sub(size);
+        let ranges = (0..count).map(|start| start..start + size).collect();
+        Segments::new(this, ranges)
+    }
+
+    /// Convert the slice into an array of length `N`, copying it if it is
+    /// borrowed. Return the slice back if its length is not `N`.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let key: GenBow<[u8], Vec<u8>> = GenBow::from(&[1, 2, 3, 4]);
+    /// assert_eq!(key.clone().try_into_array::<4>(), Ok([1, 2, 3, 4]));
+    /// assert!(key.try_into_array::<8>().is_err());
+    /// ```
+    pub fn try_into_array<const N: usize>(self) -> Result<[T; N], Self> {
+        match self {
+            GenBow::Owned(v) => <[T; N]>::try_from(v).map_err(GenBow::Owned),
+            GenBow::Borrowed(b) => <&[T; N]>::try_from(b)
+                .cloned()
+                .map_err(|_| GenBow::Borrowed(b)),
+        }
+    }
+
+    /// Sort the slice, copying it only if it is borrowed and not already
+    /// sorted.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let sorted: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2, 3]);
+    /// assert!(GenBow::extract(sorted.sorted()).is_none());
+    ///
+    /// let shuffled: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[2, 3, 1]);
+    /// assert_eq!(GenBow::extract(shuffled.sorted()), Some(vec![1, 2, 3]));
+    /// ```
+    pub fn sorted(self) -> Self
+    where
+        T: Ord,
+    {
+        self.sorted_by(Ord::cmp)
+    }
+
+    /// Sort the slice with the comparison function `compare`, copying it only
+    /// if it is borrowed and not already sorted.
+    pub fn sorted_by<F>(mut self, mut compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self
+            .windows(2)
+            .any(|w| compare(&w[0], &w[1]) == Ordering::Greater)
+        {
+            GenBow::to_mut(&mut self).sort_by(compare);
+        }
+        self
+    }
+
+    /// Keep only the elements for which `keep` returns `true`, copying the
+    /// slice only if it is borrowed and some element is removed. `keep` is
+    /// called exactly once per element, in order.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    ///
+    /// let mut values: GenBow<[i32], Vec<i32>> = GenBow::Borrowed(&[1, 2, 3]);
+    /// values.retain(|&v| v > 0);
+    /// assert!(GenBow::extract(values.clone()).is_none());
+    /// values.retain(|&v| v != 2);
+    /// assert_eq!(GenBow::extract(values), Some(vec![1, 3]));
+    /// ```
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match *self {
+            GenBow::Owned(ref mut v) => v.retain(keep),
+            GenBow::Borrowed(b) => {
+                if let Some(removed) = b.iter().position(|t| !keep(t)) {
+                    let mut v = b[..removed].to_vec();
+                    v.extend(b[removed + 1..].iter().filter(|t| keep(t)).cloned());
+                    *self = GenBow::Owned(v);
+                }
+            }
+        }
+    }
+
+    /// Consuming version of [`retain`].
+    ///
+    /// [`retain`]: GenBow::retain
+    pub fn filtered<F>(mut self, keep: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain(keep);
+        self
+    }
+
+    /// Remove consecutive repeated elements, copying the slice only if it is
+    /// borrowed and has any.
+    pub fn deduped(mut self) -> Self
+    where
+        T: PartialEq,
+    {
+        if self.windows(2).any(|w| w[0] == w[1]) {
+            GenBow::to_mut(&mut self).dedup();
+        }
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> GenBow<'a, OsStr, OsString> {
+    /// Consume the enclosed string and return it, copying it if it is
+    /// borrowed.
+    pub fn into_os_string(self) -> OsString {
+        GenBow::into_owned(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> GenBow<'a, Path, PathBuf> {
+    /// Consume the enclosed path and return it, copying it if it is
+    /// borrowed.
+    pub fn into_path_buf(self) -> PathBuf {
+        GenBow::into_owned(self)
+    }
+
+    /// Remove the prefix `base` from the path, as [`Path::strip_prefix`]
+    /// does. A borrowed path stays borrowed.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    /// use std::path::Path;
+    ///
+    /// let path: GenBow<Path, _> = GenBow::Borrowed(Path::new("/srv/boow/README.md"));
+    /// let relative = GenBow::strip_prefix(path, "/srv").unwrap();
+    /// assert_eq!(relative, GenBow::Borrowed(Path::new("boow/README.md")));
+    /// ```
+    ///
+    /// [`Path::strip_prefix`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.strip_prefix
+    pub fn strip_prefix<P: AsRef<Path>>(this: Self, base: P) -> Result<Self, StripPrefixError> {
+        match this {
+            GenBow::Borrowed(b) => b.strip_prefix(base).map(GenBow::Borrowed),
+            GenBow::Owned(o) => {
+                let stripped = o.strip_prefix(base)?;
+                if stripped.as_os_str().len() == o.as_os_str().len() {
+                    Ok(GenBow::Owned(o))
+                } else {
+                    Ok(GenBow::Owned(stripped.to_path_buf()))
+                }
+            }
+        }
+    }
+
+    /// Normalize the path lexically, as [`Path::components`] does: repeated
+    /// separators and `.` components other than a leading one are removed,
+    /// as is a trailing separator. `..` components are kept, as they may
+    /// cross symbolic links. The path is only copied if it changes.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    /// use std::path::Path;
+    ///
+    /// let normal: GenBow<Path, _> = GenBow::Borrowed(Path::new("/srv/boow"));
+    /// assert!(GenBow::extract(normal.normalized()).is_none());
+    ///
+    /// let messy: GenBow<Path, _> = GenBow::Borrowed(Path::new("/srv//./boow/"));
+    /// let normalized = GenBow::extract(messy.normalized()).unwrap();
+    /// assert_eq!(normalized.as_os_str(), "/srv/boow");
+    /// ```
+    ///
+    /// [`Path::components`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.components
+    pub fn normalized(self) -> Self {
+        if is_normalized(&self) {
+            self
+        } else {
+            GenBow::Owned(self.components().collect())
+        }
+    }
+
+    /// Append a trailing separator to the path, copying it only if it does
+    /// not end with one already. An empty path is left unchanged, as it would
+    /// otherwise become the root.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::GenBow;
+    /// use std::path::Path;
+    ///
+    /// let dir: GenBow<Path, _> = GenBow::Borrowed(Path::new("boow/"));
+    /// assert!(GenBow::extract(dir.with_trailing_separator()).is_none());
+    ///
+    /// let empty: GenBow<Path, _> = GenBow::Borrowed(Path::new(""));
+    /// assert_eq!(*empty.with_trailing_separator(), *Path::new(""));
+    /// ```
+    pub fn with_trailing_separator(self) -> Self {
+        match self.as_os_str().as_encoded_bytes().last() {
+            None => self,
+            Some(&last) if path::is_separator(last as char) => self,
+            _ => {
+                let mut s = GenBow::into_owned(self).into_os_string();
+                s.push(path::MAIN_SEPARATOR_STR);
+                GenBow::Owned(PathBuf::from(s))
+            }
+        }
+    }
+}
+
+/// Whether `path` is spelled exactly as the concatenation of its components.
+#[cfg(feature = "std")]
+fn is_normalized(path: &Path) -> bool {
+    let mut rest = path.as_os_str().as_encoded_bytes();
+    let mut needs_separator = false;
+    for component in path.components() {
+        if needs_separator {
+            match rest.split_first() {
+                Some((&b, tail)) if path::is_separator(b as char) => rest = tail,
+                _ => return false,
+            }
+        }
+        let bytes = component.as_os_str().as_encoded_bytes();
+        if !rest.starts_with(bytes) {
+            return false;
+        }
+        rest = &rest[bytes.len()..];
+        needs_separator = !matches!(component, Component::Prefix(_) | Component::RootDir);
+    }
+    rest.is_empty()
+}
+
+impl<'a, B: ?Sized + 'a, O> Clone for GenBow<'a, B, O>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            GenBow::Owned(ref o) => GenBow::Owned(o.clone()),
+            GenBow::Borrowed(b) => GenBow::Borrowed(b),
+        }
+    }
+
+    /// Reuse the resources of an owned value, e.g. the capacity of a
+    /// `String`, if both values are owned.
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (GenBow::Owned(o), GenBow::Owned(s)) => o.clone_from(s),
+            (this, source) => *this = source.clone(),
+        }
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> Eq for GenBow<'a, B, O>
+where
+    B: Eq,
+    O: Borrow<B>,
+{
+}
+
+impl<'a, B: ?Sized + 'a, O> Ord for GenBow<'a, B, O>
+where
+    B: Ord,
+    O: Borrow<B>,
+{
+    fn cmp(&self, other: &GenBow<'a, B, O>) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> PartialEq for GenBow<'a, B, O>
+where
+    B: PartialEq,
+    O: Borrow<B>,
+{
+    fn eq(&self, other: &GenBow<'a, B, O>) -> bool {
+        PartialEq::eq(&**self, &**other)
+    }
+}
+
+macro_rules! impl_eq {
+    ($([$($params:tt)*] $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($params)*> PartialEq<$rhs> for $lhs {
+                fn eq(&self, other: &$rhs) -> bool {
+                    PartialEq::eq(&self[..], &other[..])
+                }
+            }
+
+            impl<$($params)*> PartialEq<$lhs> for $rhs {
+                fn eq(&self, other: &$lhs) -> bool {
+                    PartialEq::eq(&self[..], &other[..])
+                }
+            }
+        )*
+    };
+}
+
+impl_eq! {
+    ['a, O: Borrow<str>] GenBow<'a, str, O>, str;
+    ['a, 'b, O: Borrow<str>] GenBow<'a, str, O>, &'b str;
+    ['a, O: Borrow<str>] GenBow<'a, str, O>, String;
+    ['a, T: PartialEq, O: Borrow<[T]>] GenBow<'a, [T], O>, [T];
+    ['a, 'b, T: PartialEq, O: Borrow<[T]>] GenBow<'a, [T], O>, &'b [T];
+    ['a, T: PartialEq, O: Borrow<[T]>] GenBow<'a, [T], O>, Vec<T>;
+}
+
+impl<'a, B: ?Sized + 'a, O> PartialOrd for GenBow<'a, B, O>
+where
+    B: PartialOrd,
+    O: Borrow<B>,
+{
+    fn partial_cmp(&self, other: &GenBow<'a, B, O>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+macro_rules! impl_ord {
+    ($([$($params:tt)*] $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($params)*> PartialOrd<$rhs> for $lhs {
+                fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                    PartialOrd::partial_cmp(&self[..], &other[..])
+                }
+            }
+
+            impl<$($params)*> PartialOrd<$lhs> for $rhs {
+                fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                    PartialOrd::partial_cmp(&self[..], &other[..])
+                }
+            }
+        )*
+    };
+}
+
+impl_ord! {
+    ['a, O: Borrow<str>] GenBow<'a, str, O>, str;
+    ['a, 'b, O: Borrow<str>] GenBow<'a, str, O>, &'b str;
+    ['a, O: Borrow<str>] GenBow<'a, str, O>, String;
+    ['a, T: PartialOrd, O: Borrow<[T]>] GenBow<'a, [T], O>, [T];
+    ['a, 'b, T: PartialOrd, O: Borrow<[T]>] GenBow<'a, [T], O>, &'b [T];
+    ['a, T: PartialOrd, O: Borrow<[T]>] GenBow<'a, [T], O>, Vec<T>;
+}
+
+impl<'a, B: ?Sized + 'a, O> fmt::Debug for GenBow<'a, B, O>
+where
+    B: fmt::Debug,
+    O: Borrow<B>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> fmt::Display for GenBow<'a, B, O>
+where
+    B: fmt::Display,
+    O: Borrow<B>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> Default for GenBow<'a, B, O>
+where
+    O: Default,
+{
+    fn default() -> Self {
+        GenBow::Owned(O::default())
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> Hash for GenBow<'a, B, O>
+where
+    B: Hash,
+    O: Borrow<B>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O> AsRef<B> for GenBow<'a, B, O>
+where
+    O: Borrow<B>,
+{
+    fn as_ref(&self) -> &B {
+        self
+    }
+}
+
+impl<'a, B: ?Sized + 'a> From<Cow<'a, B>> for GenBow<'a, B>
+where
+    B: ToOwned,
+{
+    fn from(cow: Cow<'a, B>) -> Self {
+        match cow {
+            Cow::Owned(o) => GenBow::Owned(o),
+            Cow::Borrowed(b) => GenBow::Borrowed(b),
+        }
+    }
+}
+
+impl<'a, B: ?Sized + 'a> From<GenBow<'a, B>> for Cow<'a, B>
+where
+    B: ToOwned,
+{
+    fn from(bow: GenBow<'a, B>) -> Self {
+        match bow {
+            GenBow::Owned(o) => Cow::Owned(o),
+            GenBow::Borrowed(b) => Cow::Borrowed(b),
+        }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> From<[T; N]> for GenBow<'a, [T], Vec<T>> {
+    fn from(array: [T; N]) -> Self {
+        GenBow::Owned(Vec::from(array))
+    }
+}
+
+impl<'a, T: 'a, const N: usize> From<&'a [T; N]> for GenBow<'a, [T], Vec<T>> {
+    fn from(array: &'a [T; N]) -> Self {
+        GenBow::Borrowed(array)
+    }
+}
+
+/// Concatenate the slices with [`GenBow::concat`]. A single borrowed slice
+/// stays borrowed.
+impl<'a, T: Clone> FromIterator<GenBow<'a, [T], Vec<T>>> for GenBow<'a, [T], Vec<T>> {
+    fn from_iter<I: IntoIterator<Item = GenBow<'a, [T], Vec<T>>>>(iter: I) -> Self {
+        GenBow::concat(iter)
+    }
+}
+
+/// Concatenate the slices with [`GenBow::concat`]. A single slice stays
+/// borrowed.
+impl<'a, T: Clone> FromIterator<&'a [T]> for GenBow<'a, [T], Vec<T>> {
+    fn from_iter<I: IntoIterator<Item = &'a [T]>>(iter: I) -> Self {
+        GenBow::concat(iter.into_iter().map(GenBow::Borrowed))
+    }
+}
+
+/// Append to the string, copying a borrowed string into an owned one first.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GenBow;
+/// use std::fmt::Write;
+///
+/// let mut message: GenBow<str, String> = GenBow::Borrowed("Hello");
+/// write!(message, ", {}!", "boow").unwrap();
+/// assert_eq!(message, "Hello, boow!");
+/// ```
+impl<'a> fmt::Write for GenBow<'a, str, String> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        GenBow::to_mut(self).push_str(s);
+        Ok(())
+    }
+}
+
+/// Append to the bytes, copying borrowed bytes into an owned [`Vec`] first.
+///
+/// To read the bytes, wrap them in an [`io::Cursor`], which implements
+/// [`io::Read`], [`io::BufRead`] and [`io::Seek`] for any `AsRef<[u8]>`,
+/// without copying them.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GenBow;
+/// use std::io::{BufRead, Cursor};
+///
+/// let bytes: GenBow<[u8], Vec<u8>> = GenBow::Borrowed(b"boow\ncow\n");
+/// let lines: Vec<String> = Cursor::new(bytes).lines().map(Result::unwrap).collect();
+/// assert_eq!(lines, ["boow", "cow"]);
+/// ```
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`io::Cursor`]: https://doc.rust-lang.org/std/io/struct.Cursor.html
+/// [`io::Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+/// [`io::BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+/// [`io::Seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html
+#[cfg(feature = "std")]
+impl<'a> io::Write for GenBow<'a, [u8], Vec<u8>> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        GenBow::to_mut(self).extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Concatenate strings. A borrowed empty string on the left gives the right
+/// one as is, and an empty string on the right is ignored, so that a
+/// borrowed string is only copied if concatenation actually occurs. An owned
+/// string on the left is always appended to, keeping its buffer.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GenBow;
+///
+/// let empty: GenBow<str, String> = GenBow::Borrowed("");
+/// let name = empty + "boow";
+/// assert_eq!(name, GenBow::Borrowed("boow"));
+///
+/// let greeting = GenBow::Borrowed("Hello, ") + name;
+/// assert_eq!(GenBow::extract(greeting), Some("Hello, boow".to_owned()));
+/// ```
+impl<'a> Add<&'a str> for GenBow<'a, str, String> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &'a str) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<'a> Add<GenBow<'a, str, String>> for GenBow<'a, str, String> {
+    type Output = Self;
+
+    fn add(mut self, rhs: GenBow<'a, str, String>) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<'a> AddAssign<&'a str> for GenBow<'a, str, String> {
+    fn add_assign(&mut self, rhs: &'a str) {
+        if let GenBow::Borrowed("") = *self {
+            *self = GenBow::Borrowed(rhs);
+        } else if !rhs.is_empty() {
+            self.reserve(rhs.len());
+            self.push_str(rhs);
+        }
+    }
+}
+
+impl<'a> AddAssign<GenBow<'a, str, String>> for GenBow<'a, str, String> {
+    fn add_assign(&mut self, rhs: GenBow<'a, str, String>) {
+        if let GenBow::Borrowed("") = *self {
+            *self = rhs;
+        } else if !rhs.is_empty() {
+            self.reserve(rhs.len());
+            self.push_str(&rhs);
+        }
+    }
+}
+
+/// Append the fragments, as `+=` does. Starting from [`EMPTY`], a [`GenBow`]
+/// thus works as a lazily allocating string builder: a single non-empty
+/// fragment stays borrowed, and an owned string is only created once a
+/// second one is appended. Starting from an owned buffer, e.g. one made with
+/// [`with_capacity`], every fragment is appended to that buffer.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GenBow;
+///
+/// let mut page = GenBow::<str, String>::EMPTY;
+/// page.extend(vec!["", "<html>", ""]);
+/// assert_eq!(page, GenBow::Borrowed("<html>"));
+/// page.extend(vec!["</html>"]);
+/// assert_eq!(GenBow::extract(page), Some("<html></html>".to_owned()));
+/// ```
+///
+/// [`EMPTY`]: GenBow::EMPTY
+/// [`with_capacity`]: GenBow::with_capacity
+impl<'a> Extend<&'a str> for GenBow<'a, str, String> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for fragment in iter {
+            *self += fragment;
+        }
+    }
+}
+
+impl<'a> Extend<GenBow<'a, str, String>> for GenBow<'a, str, String> {
+    fn extend<I: IntoIterator<Item = GenBow<'a, str, String>>>(&mut self, iter: I) {
+        for fragment in iter {
+            *self += fragment;
+        }
+    }
+}
+
+impl<'a> Extend<char> for GenBow<'a, str, String> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().peekable();
+        if iter.peek().is_some() {
+            GenBow::to_mut(self).extend(iter);
+        }
+    }
+}
+
+impl<'a> FromIterator<char> for GenBow<'a, str, String> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        GenBow::Owned(iter.into_iter().collect())
+    }
+}
+
+/// Concatenate the fragments, as [`Extend`] does starting from a borrowed
+/// empty string. Empty fragments are skipped, so that a single non-empty
+/// borrowed fragment stays borrowed, and no fragment gives a borrowed empty
+/// string.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GenBow;
+///
+/// let single: GenBow<str, String> = vec!["", "boow", ""].into_iter().collect();
+/// assert!(GenBow::extract(single).is_none());
+///
+/// let many: GenBow<str, String> = "b o o w".split(' ').collect();
+/// assert_eq!(GenBow::extract(many), Some("boow".to_owned()));
+/// ```
+impl<'a> FromIterator<GenBow<'a, str, String>> for GenBow<'a, str, String> {
+    fn from_iter<I: IntoIterator<Item = GenBow<'a, str, String>>>(iter: I) -> Self {
+        let mut concatenated = GenBow::Borrowed("");
+        concatenated.extend(iter);
+        concatenated
+    }
+}
+
+/// Concatenate the fragments. A single fragment stays borrowed.
+impl<'a> FromIterator<&'a str> for GenBow<'a, str, String> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().map(GenBow::Borrowed).collect()
+    }
+}
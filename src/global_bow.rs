@@ -0,0 +1,69 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use Bow;
+
+/// Process-wide default value, lazily initialized and handed out as
+/// borrowed [`Bow`]s.
+///
+/// The default is built with the function given to [`new`] on first use,
+/// unless it was overridden with [`set`] beforehand, e.g. at the start of a
+/// test.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, GlobalBow};
+///
+/// static GREETING: GlobalBow<String> = GlobalBow::new(|| "Hello".to_owned());
+///
+/// fn greeting(custom: Option<String>) -> Bow<'static, String> {
+///     custom.map_or_else(|| GREETING.get(), Bow::Owned)
+/// }
+///
+/// assert_eq!(GREETING.set("Hi".to_owned()), Ok(()));
+/// assert!(Bow::is_borrowed(&greeting(None)));
+/// assert_eq!(*greeting(None), "Hi");
+/// ```
+///
+/// [`Bow`]: crate::Bow
+/// [`new`]: GlobalBow::new
+/// [`set`]: GlobalBow::set
+pub struct GlobalBow<T> {
+    cell: OnceLock<T>,
+    init: fn() -> T,
+}
+
+impl<T> GlobalBow<T> {
+    /// Create a default value built with `init` on first use.
+    pub const fn new(init: fn() -> T) -> Self {
+        GlobalBow {
+            cell: OnceLock::new(),
+            init,
+        }
+    }
+
+    /// Borrow the default value, building it first if needed.
+    pub fn get(&'static self) -> Bow<'static, T> {
+        Bow::Borrowed(self.cell.get_or_init(self.init))
+    }
+
+    /// Override the default value. Fail and return `t` if the default value
+    /// was already built or set.
+    pub fn set(&self, t: T) -> Result<(), T> {
+        self.cell.set(t)
+    }
+
+    /// Return whether the default value was already built or set.
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+impl<T> fmt::Debug for GlobalBow<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GlobalBow").field(&self.cell.get()).finish()
+    }
+}
@@ -0,0 +1,137 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        #[cfg(feature = "borrow")]
+        use std::borrow::Borrow;
+        use std::ops::Deref;
+    } else {
+        #[cfg(feature = "borrow")]
+        use alloc::borrow::Borrow;
+        use alloc::boxed::Box;
+        use core::ops::Deref;
+    }
+}
+
+use {Bow, MakeOwned};
+
+/// Borrow-Or-oWned smart pointer with an additional variant keeping a guard
+/// alive.
+///
+/// Like [`Bow`], but the enclosed value can also be reached through a guard
+/// such as a [`MutexGuard`], a [`RwLockReadGuard`] or a [`Ref`]. This way,
+/// a function can return either data straight from a lock or a computed
+/// owned value, without cloning out of the lock.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::GuardBow;
+/// use std::sync::Mutex;
+///
+/// fn name<'a>(cache: &'a Mutex<String>, id: u32) -> GuardBow<'a, String> {
+///     if id == 0 {
+///         GuardBow::guarded(cache.lock().unwrap())
+///     } else {
+///         GuardBow::Owned(format!("user{}", id))
+///     }
+/// }
+///
+/// let cache = Mutex::new("root".to_owned());
+/// assert_eq!(*name(&cache, 0), "root");
+/// assert_eq!(*name(&cache, 1), "user1");
+/// ```
+///
+/// [`Bow`]: crate::Bow
+/// [`MutexGuard`]: https://doc.rust-lang.org/std/sync/struct.MutexGuard.html
+/// [`RwLockReadGuard`]: https://doc.rust-lang.org/std/sync/struct.RwLockReadGuard.html
+/// [`Ref`]: https://doc.rust-lang.org/std/cell/struct.Ref.html
+pub enum GuardBow<'a, T: 'a> {
+    Owned(T),
+    Borrowed(&'a T),
+    Guarded(Box<dyn Deref<Target = T> + 'a>),
+}
+
+#[cfg(feature = "borrow")]
+impl<'a, T: 'a> Borrow<T> for GuardBow<'a, T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<'a, T: 'a> Deref for GuardBow<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match *self {
+            GuardBow::Owned(ref t) => t,
+            GuardBow::Borrowed(t) => t,
+            GuardBow::Guarded(ref guard) => guard,
+        }
+    }
+}
+
+impl<'a, T: 'a> GuardBow<'a, T> {
+    /// Wrap a guard dereferencing to the value, boxing it.
+    pub fn guarded<G>(guard: G) -> Self
+    where
+        G: Deref<Target = T> + 'a,
+    {
+        GuardBow::Guarded(Box::new(guard))
+    }
+
+    /// Return whether the value is reached through a guard.
+    pub fn is_guarded(this: &Self) -> bool {
+        matches!(*this, GuardBow::Guarded(_))
+    }
+
+    /// Get a mutable reference to the enclosed value. Return [`None`] if the
+    /// value is not owned.
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut T> {
+        match *this {
+            GuardBow::Owned(ref mut t) => Some(t),
+            GuardBow::Borrowed(_) | GuardBow::Guarded(_) => None,
+        }
+    }
+
+    /// Consume the enclosed value and return it if it is owned. A guard is
+    /// released.
+    pub fn extract(this: Self) -> Option<T> {
+        match this {
+            GuardBow::Owned(t) => Some(t),
+            GuardBow::Borrowed(_) | GuardBow::Guarded(_) => None,
+        }
+    }
+
+    /// Consume the enclosed value and return it, making an owned duplicate
+    /// with [`MakeOwned`] if it is borrowed or guarded. A guard is released.
+    pub fn into_owned(this: Self) -> T
+    where
+        T: MakeOwned,
+    {
+        match this {
+            GuardBow::Owned(t) => t,
+            GuardBow::Borrowed(t) => t.make_owned(),
+            GuardBow::Guarded(guard) => (**guard).make_owned(),
+        }
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: 'a] GuardBow<'a, T> => T:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+}
+
+impl<'a, T: 'a> Default for GuardBow<'a, T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        GuardBow::Owned(T::default())
+    }
+}
+
+impl<'a, T: 'a> From<Bow<'a, T>> for GuardBow<'a, T> {
+    fn from(bow: Bow<'a, T>) -> Self {
+        match bow {
+            Bow::Owned(t) => GuardBow::Owned(t),
+            Bow::Borrowed(t) => GuardBow::Borrowed(t),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Heap usage of owned values, available with the `heap-size` feature.
+
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::ffi::{OsStr, OsString};
+        use std::mem;
+        use std::path::{Path, PathBuf};
+        use std::rc::Rc;
+        use std::sync::Arc;
+    } else {
+        use alloc::boxed::Box;
+        use alloc::rc::Rc;
+        use alloc::string::String;
+        use alloc::sync::Arc;
+        use alloc::vec::Vec;
+        use core::mem;
+    }
+}
+
+use {Bow, BowMutSlice, BowOption, BowOrBox, GenBow, GuardBow, SharedBow, SmallBow};
+
+/// Number of heap bytes exclusively owned by a value.
+///
+/// Borrowed values and values behind shared pointers such as [`Rc`] or
+/// [`Arc`] are attributed zero bytes, so that the heap usage of a data
+/// structure only accounts for what it owns.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, HeapSize};
+///
+/// let name = String::with_capacity(16);
+/// assert_eq!(Bow::Borrowed(&name).heap_size(), 0);
+/// assert_eq!(Bow::Owned(name).heap_size(), 16);
+/// ```
+///
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+pub trait HeapSize {
+    /// Return the number of heap bytes owned by the value, excluding the
+    /// size of the value itself.
+    fn heap_size(&self) -> usize;
+}
+
+macro_rules! impl_heap_size_zero {
+    ($($t:ty),*) => {
+        $(
+            impl HeapSize for $t {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_heap_size_zero!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    str
+);
+
+impl<T: ?Sized> HeapSize for &T {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl<T: ?Sized> HeapSize for Rc<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl<T: ?Sized> HeapSize for Arc<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+#[cfg(feature = "std")]
+impl_heap_size_zero!(OsStr, Path);
+
+#[cfg(feature = "std")]
+impl HeapSize for OsString {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+/// ```rust
+/// extern crate boow;
+/// use boow::{GenBow, HeapSize};
+/// use std::path::{Path, PathBuf};
+///
+/// let path = PathBuf::with_capacity(16);
+/// assert_eq!(GenBow::<Path>::Borrowed(&path).heap_size(), 0);
+/// assert_eq!(GenBow::<Path>::Owned(path).heap_size(), 16);
+/// ```
+#[cfg(feature = "std")]
+impl HeapSize for PathBuf {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: HeapSize> HeapSize for [T] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(HeapSize::heap_size).sum()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self[..].heap_size()
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        mem::size_of_val(&**self) + (**self).heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl<'a, T: HeapSize + 'a> HeapSize for Bow<'a, T> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            Bow::Owned(ref t) => t.heap_size(),
+            Bow::Borrowed(_) => 0,
+        }
+    }
+}
+
+impl<'a, T: HeapSize + 'a> HeapSize for BowOption<'a, T> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            BowOption::Owned(ref t) => t.heap_size(),
+            BowOption::None | BowOption::Borrowed(_) => 0,
+        }
+    }
+}
+
+impl<'a, T: HeapSize + 'a> HeapSize for BowOrBox<'a, T> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            BowOrBox::Owned(ref t) => t.heap_size(),
+            BowOrBox::Borrowed(_) => 0,
+            BowOrBox::Boxed(ref t) => t.heap_size(),
+        }
+    }
+}
+
+impl<'a, T: HeapSize + 'a, const N: usize> HeapSize for SmallBow<'a, T, N> {
+    fn heap_size(&self) -> usize {
+        if SmallBow::is_borrowed(self) {
+            0
+        } else if SmallBow::is_inline(self) {
+            (**self).heap_size()
+        } else {
+            mem::size_of::<T>() + (**self).heap_size()
+        }
+    }
+}
+
+impl<'a, B: ?Sized + 'a, O: HeapSize> HeapSize for GenBow<'a, B, O> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            GenBow::Owned(ref o) => o.heap_size(),
+            GenBow::Borrowed(_) => 0,
+        }
+    }
+}
+
+impl<'a, T: HeapSize + 'a, P> HeapSize for SharedBow<'a, T, P> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            SharedBow::Owned(ref t) => t.heap_size(),
+            SharedBow::Borrowed(_) | SharedBow::Shared(_) => 0,
+        }
+    }
+}
+
+/// Guarded values are attributed zero bytes, as the guard is opaque.
+impl<'a, T: HeapSize + 'a> HeapSize for GuardBow<'a, T> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            GuardBow::Owned(ref t) => t.heap_size(),
+            GuardBow::Borrowed(_) | GuardBow::Guarded(_) => 0,
+        }
+    }
+}
+
+impl<'a, T: HeapSize + 'a> HeapSize for BowMutSlice<'a, T> {
+    fn heap_size(&self) -> usize {
+        match *self {
+            BowMutSlice::Owned(ref v) => v.heap_size(),
+            BowMutSlice::Borrowed(_) => 0,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::fmt;
+        use std::hash::{Hash, Hasher};
+    } else {
+        use core::fmt;
+        use core::hash::{Hash, Hasher};
+    }
+}
+
+/// String wrapper comparing and hashing ASCII case-insensitively, without
+/// allocating lowercase copies.
+///
+/// Typically wraps a [`GenBow`] string, e.g. to key a map of HTTP headers.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{GenBow, IgnoreAsciiCase};
+/// use std::collections::HashMap;
+///
+/// let mut headers = HashMap::new();
+/// let name: GenBow<'static, str> = GenBow::Owned("Content-Type".to_owned());
+/// headers.insert(IgnoreAsciiCase(name), "text/plain");
+///
+/// let lookup = IgnoreAsciiCase(GenBow::Borrowed("content-type"));
+/// assert_eq!(headers.get(&lookup), Some(&"text/plain"));
+/// ```
+///
+/// [`GenBow`]: crate::GenBow
+#[derive(Clone, Copy, Default)]
+pub struct IgnoreAsciiCase<S>(pub S);
+
+impl<S> IgnoreAsciiCase<S> {
+    /// Consume the wrapper and return the string.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: AsRef<str>> PartialEq for IgnoreAsciiCase<S> {
+    fn eq(&self, other: &IgnoreAsciiCase<S>) -> bool {
+        self.0.as_ref().eq_ignore_ascii_case(other.0.as_ref())
+    }
+}
+
+impl<S: AsRef<str>> Eq for IgnoreAsciiCase<S> {}
+
+impl<S: AsRef<str>> Hash for IgnoreAsciiCase<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.0.as_ref().bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        // Like `str`, terminate with a byte which cannot appear in UTF-8.
+        state.write_u8(0xff);
+    }
+}
+
+impl<S: AsRef<str>> AsRef<str> for IgnoreAsciiCase<S> {
+    fn as_ref(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl<S> From<S> for IgnoreAsciiCase<S> {
+    fn from(s: S) -> Self {
+        IgnoreAsciiCase(s)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for IgnoreAsciiCase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for IgnoreAsciiCase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
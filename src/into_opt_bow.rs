@@ -0,0 +1,104 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::borrow::Cow;
+    } else {
+        use alloc::borrow::Cow;
+        use alloc::boxed::Box;
+    }
+}
+
+use {Bow, BowOption};
+
+/// Conversion into an optional [`Bow`].
+///
+/// Lets a function accept an optional owned or borrowed parameter with a
+/// single generic parameter.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, IntoOptBow};
+///
+/// fn greet<'a, N: IntoOptBow<'a, String>>(name: N) -> String {
+///     match name.into_opt_bow() {
+///         Some(name) => format!("Hello, {}!", *name),
+///         None => "Hello!".to_owned(),
+///     }
+/// }
+///
+/// let name = "boow".to_owned();
+/// assert_eq!(greet(Some(&name)), "Hello, boow!");
+/// assert_eq!(greet(Some(name.clone())), "Hello, boow!");
+/// assert_eq!(greet(&name), "Hello, boow!");
+/// assert_eq!(greet(None::<String>), "Hello!");
+/// ```
+///
+/// [`Rc`] and [`Arc`] are not accepted, since a shared pointer could only be
+/// converted by silently duplicating its value. Use [`Bow::from_rc`] or
+/// [`Bow::from_arc`], which fail if the pointer is shared.
+///
+/// [`Bow`]: crate::Bow
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Bow::from_rc`]: crate::Bow::from_rc
+/// [`Bow::from_arc`]: crate::Bow::from_arc
+pub trait IntoOptBow<'a, T: 'a> {
+    /// Convert into an optional [`Bow`].
+    fn into_opt_bow(self) -> Option<Bow<'a, T>>;
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for T {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        Some(Bow::Owned(self))
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for &'a T {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        Some(Bow::Borrowed(self))
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for Bow<'a, T> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        Some(self)
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for Option<T> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        self.map(Bow::Owned)
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for Option<&'a T> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        self.map(Bow::Borrowed)
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for Option<Bow<'a, T>> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        self
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for BowOption<'a, T> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        self.into_option()
+    }
+}
+
+impl<'a, T: 'a> IntoOptBow<'a, T> for Box<T> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        Some(Bow::Owned(*self))
+    }
+}
+
+impl<'a, T: Clone + 'a> IntoOptBow<'a, T> for Cow<'a, T> {
+    fn into_opt_bow(self) -> Option<Bow<'a, T>> {
+        match self {
+            Cow::Owned(t) => Some(Bow::Owned(t)),
+            Cow::Borrowed(t) => Some(Bow::Borrowed(t)),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::fmt;
+    } else {
+        use core::fmt;
+    }
+}
+
+use {Bow, BowOption, IntoOptBow};
+
+/// Resolution of a chain of optional layers into a single [`Bow`], the first
+/// layer holding a value winning.
+///
+/// Typical for configuration values, e.g. a user override falling back to
+/// the project configuration, then to a built-in default.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, Layered};
+///
+/// const DEFAULT_EDITOR: &String = &String::new();
+///
+/// let user: Option<String> = None;
+/// let project = "vi".to_owned();
+///
+/// let editor = Layered::new()
+///     .layer(user)
+///     .layer(Some(&project))
+///     .or_borrowed(DEFAULT_EDITOR);
+/// assert!(Bow::is_borrowed(&editor));
+/// assert_eq!(*editor, "vi");
+/// ```
+///
+/// [`Bow`]: crate::Bow
+pub struct Layered<'a, T: 'a> {
+    resolved: BowOption<'a, T>,
+}
+
+impl<'a, T: 'a> Layered<'a, T> {
+    /// Create a chain without any layer.
+    pub fn new() -> Self {
+        Layered {
+            resolved: BowOption::None,
+        }
+    }
+
+    /// Add a layer, used if no previous layer holds a value.
+    pub fn layer<L: IntoOptBow<'a, T>>(self, layer: L) -> Self {
+        self.layer_with(|| layer)
+    }
+
+    /// Add a layer computed with `f`, only called if no previous layer holds
+    /// a value.
+    pub fn layer_with<L, F>(self, f: F) -> Self
+    where
+        L: IntoOptBow<'a, T>,
+        F: FnOnce() -> L,
+    {
+        match self.resolved {
+            BowOption::None => Layered {
+                resolved: f().into_opt_bow().into(),
+            },
+            _ => self,
+        }
+    }
+
+    /// Return whether a layer holds a value.
+    pub fn is_resolved(&self) -> bool {
+        self.resolved.is_some()
+    }
+
+    /// Get the value of the first layer holding one, if any.
+    pub fn resolve(self) -> Option<Bow<'a, T>> {
+        self.resolved.into_option()
+    }
+
+    /// Get the value of the first layer holding one, or borrow `default` if
+    /// there is none.
+    pub fn or_borrowed(self, default: &'a T) -> Bow<'a, T> {
+        self.resolved.or_borrowed(default)
+    }
+}
+
+impl<'a, T: 'a> Default for Layered<'a, T> {
+    fn default() -> Self {
+        Layered::new()
+    }
+}
+
+impl<'a, T: 'a> fmt::Debug for Layered<'a, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Layered").field(&self.resolved).finish()
+    }
+}
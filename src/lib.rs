@@ -40,24 +40,166 @@
 //! [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(feature = "std"), feature(alloc))]
+#![cfg_attr(
+    feature = "nightly-const",
+    feature(const_trait_impl, const_convert, const_cmp)
+)]
 
 #[macro_use]
 extern crate cfg_if;
+#[cfg(feature = "derive")]
+extern crate boow_derive;
 
 cfg_if! {
     if #[cfg(feature = "std")] {
+        #[cfg(feature = "borrow")]
         use std::borrow::Borrow;
         use std::cmp::Ordering;
+        use std::convert::TryInto;
+        use std::error::Error;
         use std::fmt;
+        use std::fs;
         use std::hash::{Hash, Hasher};
+        #[cfg(feature = "unchecked")]
+        use std::hint;
+        use std::io;
+        use std::iter::{Product, Sum};
+        use std::net;
         use std::ops::Deref;
+        #[cfg(feature = "deref-mut-cow")]
+        use std::ops::DerefMut;
+        use std::rc::Rc;
+        use std::str::FromStr;
+        use std::sync::Arc;
     } else {
         extern crate alloc;
+        #[cfg(feature = "borrow")]
         use alloc::borrow::Borrow;
+        use alloc::boxed::Box;
+        use alloc::rc::Rc;
+        use alloc::sync::Arc;
         use core::cmp::Ordering;
+        use core::convert::TryInto;
+        use core::error::Error;
         use core::fmt;
         use core::hash::{Hash, Hasher};
+        #[cfg(feature = "unchecked")]
+        use core::hint;
+        use core::iter::{Product, Sum};
         use core::ops::Deref;
+        #[cfg(feature = "deref-mut-cow")]
+        use core::ops::DerefMut;
+        use core::str::FromStr;
+    }
+}
+
+#[macro_use]
+mod macros;
+
+mod auto_traits;
+#[cfg(feature = "std")]
+mod bow_cache;
+mod bow_dyn;
+mod bow_iter;
+mod bow_mut_slice;
+mod bow_option;
+mod bow_or_box;
+mod cached_bow;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(doctest)]
+mod compile_fail;
+#[cfg(feature = "std")]
+pub mod env;
+mod gen_bow;
+#[cfg(feature = "std")]
+mod global_bow;
+mod guard_bow;
+#[cfg(feature = "heap-size")]
+mod heap_size;
+mod ignore_ascii_case;
+mod into_opt_bow;
+mod layered;
+mod map;
+#[cfg(feature = "nightly-const")]
+mod nightly_const;
+#[cfg(feature = "std")]
+mod os;
+mod pin_bow;
+mod shared_bow;
+mod small_bow;
+mod try_as_mut;
+pub mod typestate;
+
+#[cfg(feature = "derive")]
+pub use boow_derive::ProjectBow;
+#[cfg(feature = "std")]
+pub use bow_cache::{BowCache, EvictionPolicy, Fifo, NoEviction};
+pub use bow_dyn::{BowAny, BowDyn, BowFn};
+pub use bow_iter::{BowIterExt, LendingBowIter, OwnedItems};
+pub use bow_mut_slice::BowMutSlice;
+pub use bow_option::BowOption;
+pub use bow_or_box::BowOrBox;
+pub use cached_bow::CachedBow;
+pub use gen_bow::{GenBow, Segments};
+#[cfg(feature = "std")]
+pub use global_bow::GlobalBow;
+pub use guard_bow::GuardBow;
+#[cfg(feature = "heap-size")]
+pub use heap_size::HeapSize;
+pub use ignore_ascii_case::IgnoreAsciiCase;
+pub use into_opt_bow::IntoOptBow;
+pub use layered::Layered;
+pub use map::MapBowExt;
+pub use pin_bow::PinBow;
+pub use shared_bow::SharedBow;
+pub use small_bow::SmallBow;
+pub use try_as_mut::TryAsMut;
+
+/// Commonly used types and traits, to be glob imported.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::prelude::*;
+///
+/// let defaults = [1, 2, 3];
+/// let values: Vec<Bow<i32>> = defaults.iter().map(borrowed).collect();
+/// assert!(values.iter().all(Bow::is_borrowed));
+/// ```
+pub mod prelude {
+    pub use {borrowed, owned};
+    pub use {Bow, BowIterExt, BowOption, GenBow, IntoOptBow, MakeOwned, StaticBow, TryMakeOwned};
+}
+
+/// Wrap an owned value in a [`Bow`].
+///
+/// Same as [`Bow::Owned`], usable as the argument of [`Iterator::map`] over
+/// values.
+pub const fn owned<'a, T: 'a>(t: T) -> Bow<'a, T> {
+    Bow::Owned(t)
+}
+
+/// Wrap a borrowed value in a [`Bow`].
+///
+/// Same as [`Bow::Borrowed`], usable as the argument of [`Iterator::map`]
+/// over references.
+pub const fn borrowed<'a, T: 'a>(t: &'a T) -> Bow<'a, T> {
+    Bow::Borrowed(t)
+}
+
+/// Items used by the code generated by [`bow_dyn!`]. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            pub use std::boxed::Box;
+            pub use std::fmt;
+            pub use std::ops::Deref;
+        } else {
+            pub use alloc::boxed::Box;
+            pub use core::fmt;
+            pub use core::ops::Deref;
+        }
     }
 }
 
@@ -68,15 +210,39 @@ cfg_if! {
 /// [`borrow_mut`] will obtain some mutable reference to an owned value, but
 /// only if it is owned.
 ///
+/// Like [`Box::leak`] or [`Rc::get_mut`], all the functions of [`Bow`] are
+/// associated functions, so that they never shadow methods of the enclosed
+/// value reached through [`Deref`] (e.g. [`RefCell::borrow_mut`] or
+/// [`Cow::to_mut`]). Call them as `Bow::borrow_mut(&mut bow)` or
+/// `Bow::into_owned(bow)`. The other members of the family follow the same
+/// rule for functions on their generic payload.
+///
 /// [`borrow_mut`]: Bow::borrow_mut
-#[derive(Copy, Clone)]
+/// [`Cow::to_mut`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html#method.to_mut
+/// [`Box::leak`]: https://doc.rust-lang.org/std/boxed/struct.Box.html#method.leak
+/// [`Rc::get_mut`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.get_mut
+/// [`RefCell::borrow_mut`]: https://doc.rust-lang.org/std/cell/struct.RefCell.html#method.borrow_mut
+#[derive(Copy)]
 pub enum Bow<'a, T: 'a> {
     Owned(T),
     Borrowed(&'a T),
 }
 
+/// A [`Bow`] whose borrowed variant lives for the whole program, e.g. a
+/// global default value.
+pub type StaticBow<T> = Bow<'static, T>;
+
+#[cfg(feature = "borrow")]
 impl<'a, T: 'a> Borrow<T> for Bow<'a, T> {
     fn borrow(&self) -> &T {
+        self
+    }
+}
+
+#[cfg(not(feature = "nightly-const"))]
+impl<'a, T: 'a> Deref for Bow<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
         match *self {
             Bow::Owned(ref t) => t,
             Bow::Borrowed(t) => t,
@@ -84,34 +250,731 @@ impl<'a, T: 'a> Borrow<T> for Bow<'a, T> {
     }
 }
 
-impl<'a, T: 'a> Deref for Bow<'a, T> {
-    type Target = T;
-    fn deref(&self) -> &T {
-        self.borrow()
+/// Mutable access promotes borrowed values to owned ones, like [`to_mut`].
+///
+/// Only available with the `deref-mut-cow` feature, as the duplicate made by
+/// [`MakeOwned`] happens silently.
+///
+/// [`to_mut`]: enum.Bow.html#method.to_mut
+/// [`MakeOwned`]: trait.MakeOwned.html
+#[cfg(feature = "deref-mut-cow")]
+impl<'a, T: MakeOwned + 'a> DerefMut for Bow<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        Bow::to_mut(self)
+    }
+}
+
+impl<T: 'static> Bow<'static, T> {
+    /// Wrap a static reference, typically to a global default value.
+    pub const fn from_static(t: &'static T) -> Self {
+        Bow::Borrowed(t)
+    }
+
+    /// Take ownership of the value of an [`Rc`] if it has exactly one strong
+    /// reference. Otherwise, return the [`Rc`] back.
+    ///
+    /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+    pub fn from_rc(rc: Rc<T>) -> Result<Self, Rc<T>> {
+        Rc::try_unwrap(rc).map(Bow::Owned)
+    }
+
+    /// Take ownership of the value of an [`Arc`] if it has exactly one strong
+    /// reference. Otherwise, return the [`Arc`] back.
+    ///
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn from_arc(arc: Arc<T>) -> Result<Self, Arc<T>> {
+        Arc::try_unwrap(arc).map(Bow::Owned)
     }
 }
 
 impl<'a, T: 'a> Bow<'a, T> {
+    /// Wrap an owned value. Can be used in `const` contexts.
+    pub const fn owned(t: T) -> Self {
+        Bow::Owned(t)
+    }
+
+    /// Wrap a borrowed value. Can be used in `const` contexts.
+    pub const fn borrowed(t: &'a T) -> Self {
+        Bow::Borrowed(t)
+    }
+
+    /// Return whether the value is owned.
+    pub const fn is_owned(this: &Self) -> bool {
+        matches!(*this, Bow::Owned(_))
+    }
+
+    /// Return whether the value is borrowed.
+    pub const fn is_borrowed(this: &Self) -> bool {
+        matches!(*this, Bow::Borrowed(_))
+    }
+
+    /// Get a reference to the enclosed value if it is owned.
+    pub const fn as_owned(this: &Self) -> Option<&T> {
+        match *this {
+            Bow::Owned(ref t) => Some(t),
+            Bow::Borrowed(_) => None,
+        }
+    }
+
+    /// Get the borrowed reference if the value is borrowed.
+    pub const fn as_borrowed(this: &Self) -> Option<&'a T> {
+        match *this {
+            Bow::Owned(_) => None,
+            Bow::Borrowed(t) => Some(t),
+        }
+    }
+
     /// Get a mutable reference to the enclosed value. Return [`None`] if the
     /// value is not owned.
-    pub fn borrow_mut(&mut self) -> Option<&mut T> {
-        match *self {
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut T> {
+        match *this {
             Bow::Owned(ref mut t) => Some(t),
             Bow::Borrowed(_) => None,
         }
     }
 
+    /// Get a mutable reference to the enclosed value. Return a
+    /// [`NotOwnedError`] if the value is not owned.
+    ///
+    /// Same as [`borrow_mut`], but can be propagated with the `?` operator.
+    ///
+    /// [`borrow_mut`]: Bow::borrow_mut
+    pub fn try_borrow_mut(this: &mut Self) -> Result<&mut T, NotOwnedError> {
+        Bow::borrow_mut(this).ok_or(NotOwnedError)
+    }
+
+    /// Get a mutable reference to the enclosed value, copying it first if it
+    /// is borrowed.
+    ///
+    /// Unlike [`borrow_mut`], this always succeeds, as copying is cheap.
+    ///
+    /// [`borrow_mut`]: Bow::borrow_mut
+    pub fn borrow_mut_or_copy(this: &mut Self) -> &mut T
+    where
+        T: Copy,
+    {
+        Bow::to_mut_with(this, |t| *t)
+    }
+
     /// Consume the enclosed value and return it if it is owned.
-    pub fn extract(self) -> Option<T> {
-        match self {
+    pub fn extract(this: Self) -> Option<T> {
+        match this {
             Bow::Owned(t) => Some(t),
             Bow::Borrowed(_) => None,
         }
     }
+
+    /// Get a raw pointer to the enclosed value.
+    ///
+    /// For borrowed values, this is the address of the referenced data. For
+    /// owned values, this is the address of the value stored in the [`Bow`],
+    /// which changes whenever the [`Bow`] is moved.
+    pub fn as_ptr(this: &Self) -> *const T {
+        &**this
+    }
+
+    /// Return whether the two [`Bow`]s point to the same value, e.g. whether
+    /// a borrowed default was overridden or not.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Bow::as_ptr(this) == Bow::as_ptr(other)
+    }
+
+    /// Borrow the target of the enclosed value, e.g. view a `Bow<Box<U>>` as a
+    /// `Bow<U>`. Like [`Option::as_deref`], the result is always borrowed.
+    ///
+    /// [`Option::as_deref`]: https://doc.rust-lang.org/std/option/enum.Option.html#method.as_deref
+    pub fn as_deref(this: &Self) -> Bow<'_, T::Target>
+    where
+        T: Deref,
+        T::Target: Sized,
+    {
+        Bow::Borrowed(&***this)
+    }
+
+    /// Apply `f` to the enclosed value if it is owned, or return `default`
+    /// if it is borrowed.
+    ///
+    /// Like [`Result::map_or`], with the owned value in place of [`Ok`].
+    ///
+    /// [`Result::map_or`]: https://doc.rust-lang.org/std/result/enum.Result.html#method.map_or
+    pub fn map_or<U, F>(this: &Self, default: U, f: F) -> U
+    where
+        F: FnOnce(&T) -> U,
+    {
+        match *this {
+            Bow::Owned(ref t) => f(t),
+            Bow::Borrowed(_) => default,
+        }
+    }
+
+    /// Apply `f` to the enclosed value if it is owned, or `default` to the
+    /// borrowed reference if it is borrowed.
+    pub fn map_or_else<U, D, F>(this: &Self, default: D, f: F) -> U
+    where
+        D: FnOnce(&'a T) -> U,
+        F: FnOnce(&T) -> U,
+    {
+        match *this {
+            Bow::Owned(ref t) => f(t),
+            Bow::Borrowed(t) => default(t),
+        }
+    }
+
+    /// Consume the [`Bow`] and return a reference to the enclosed value.
+    ///
+    /// Borrowed references are returned as is, while owned values are moved
+    /// to the heap and leaked, like with [`Box::leak`].
+    ///
+    /// [`Box::leak`]: https://doc.rust-lang.org/std/boxed/struct.Box.html#method.leak
+    pub fn leak(this: Self) -> &'a T {
+        match this {
+            Bow::Owned(t) => Box::leak(Box::new(t)),
+            Bow::Borrowed(t) => t,
+        }
+    }
+
+    /// Consume the enclosed value and return it if it is owned. Otherwise,
+    /// return an [`IntoOwnedError`] holding the original borrowed reference.
+    pub fn try_into_owned(this: Self) -> Result<T, IntoOwnedError<'a, T>> {
+        match this {
+            Bow::Owned(t) => Ok(t),
+            Bow::Borrowed(t) => Err(IntoOwnedError { borrowed: t }),
+        }
+    }
+
+    /// Convert into a [`Result`] holding the owned value, or the borrowed
+    /// reference as error.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::Bow;
+    ///
+    /// let fallback = 0;
+    /// let value = Bow::into_result(Bow::Borrowed(&fallback)).unwrap_or_else(|t| *t + 1);
+    /// assert_eq!(value, 1);
+    /// ```
+    pub fn into_result(this: Self) -> Result<T, &'a T> {
+        match this {
+            Bow::Owned(t) => Ok(t),
+            Bow::Borrowed(t) => Err(t),
+        }
+    }
+
+    /// Consume the enclosed value and return it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not owned.
+    #[track_caller]
+    pub fn unwrap_owned(this: Self) -> T {
+        Bow::expect_owned(this, "called `Bow::unwrap_owned()` on a `Borrowed` value")
+    }
+
+    /// Consume the enclosed value and return it.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message `msg` if the value is not owned.
+    #[track_caller]
+    pub fn expect_owned(this: Self, msg: &str) -> T {
+        match this {
+            Bow::Owned(t) => t,
+            Bow::Borrowed(_) => panic!("{}", msg),
+        }
+    }
+
+    /// Consume the enclosed value and return it, without checking that it is
+    /// owned.
+    ///
+    /// Only available with the `unchecked` feature.
+    ///
+    /// # Safety
+    ///
+    /// The value must be owned. Calling this method on a borrowed value is
+    /// undefined behavior.
+    #[cfg(feature = "unchecked")]
+    pub unsafe fn owned_unchecked(this: Self) -> T {
+        match this {
+            Bow::Owned(t) => t,
+            Bow::Borrowed(_) => hint::unreachable_unchecked(),
+        }
+    }
+
+    /// Get the borrowed reference, without checking that the value is
+    /// borrowed.
+    ///
+    /// Only available with the `unchecked` feature.
+    ///
+    /// # Safety
+    ///
+    /// The value must be borrowed. Calling this method on an owned value is
+    /// undefined behavior.
+    #[cfg(feature = "unchecked")]
+    pub unsafe fn borrowed_unchecked(this: &Self) -> &'a T {
+        match *this {
+            Bow::Owned(_) => hint::unreachable_unchecked(),
+            Bow::Borrowed(t) => t,
+        }
+    }
+
+    /// Call `f` with a reference to the enclosed value, then return `self`
+    /// for chaining.
+    pub fn inspect<F>(this: &Self, f: F) -> &Self
+    where
+        F: FnOnce(&T),
+    {
+        f(this);
+        this
+    }
+
+    /// Call `f` with a mutable reference to the enclosed value if it is
+    /// owned, then return `self` for chaining.
+    pub fn inspect_owned<F>(this: &mut Self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut T),
+    {
+        if let Bow::Owned(ref mut t) = *this {
+            f(t);
+        }
+        this
+    }
+
+    /// Call `f` with a mutable reference to the enclosed value if it is
+    /// owned. Return whether `f` was called.
+    pub fn with_mut<F>(this: &mut Self, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        match *this {
+            Bow::Owned(ref mut t) => {
+                f(t);
+                true
+            }
+            Bow::Borrowed(_) => false,
+        }
+    }
+
+    /// Mutate the enclosed value with `mutate` if it is owned. Otherwise,
+    /// build a new owned value from the borrowed one with `rebuild` and store
+    /// it in place of the borrowed reference.
+    pub fn update_or_replace<M, R>(this: &mut Self, mutate: M, rebuild: R)
+    where
+        M: FnOnce(&mut T),
+        R: FnOnce(&T) -> T,
+    {
+        match *this {
+            Bow::Owned(ref mut t) => mutate(t),
+            Bow::Borrowed(t) => *this = Bow::Owned(rebuild(t)),
+        }
+    }
+
+    /// Convert a borrowed value into an owned one using `f` to duplicate it.
+    /// Owned values are returned as is.
+    ///
+    /// This allows to get an owned value out of types that do not implement
+    /// [`Clone`].
+    pub fn promote_with<F>(this: Self, f: F) -> Bow<'a, T>
+    where
+        F: FnOnce(&T) -> T,
+    {
+        match this {
+            Bow::Owned(t) => Bow::Owned(t),
+            Bow::Borrowed(t) => Bow::Owned(f(t)),
+        }
+    }
+
+    /// Transform an owned value with `f`. Borrowed values are returned as
+    /// is.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::Bow;
+    ///
+    /// let shared = "  kept  ".to_owned();
+    /// let trim = |s: String| s.trim().to_owned();
+    /// assert_eq!(*Bow::map_owned(Bow::Owned("  trimmed  ".to_owned()), trim), "trimmed");
+    /// assert_eq!(*Bow::map_owned(Bow::Borrowed(&shared), trim), "  kept  ");
+    /// ```
+    pub fn map_owned<F>(this: Self, f: F) -> Bow<'a, T>
+    where
+        F: FnOnce(T) -> T,
+    {
+        match this {
+            Bow::Owned(t) => Bow::Owned(f(t)),
+            Bow::Borrowed(t) => Bow::Borrowed(t),
+        }
+    }
+
+    /// In-place version of [`promote_with`].
+    ///
+    /// [`promote_with`]: Bow::promote_with
+    pub fn promote_in_place_with<F>(this: &mut Self, f: F)
+    where
+        F: FnOnce(&T) -> T,
+    {
+        if let Bow::Borrowed(t) = *this {
+            *this = Bow::Owned(f(t));
+        }
+    }
+
+    /// Get a mutable reference to the enclosed value, using `f` to make an
+    /// owned duplicate first if the value is borrowed.
+    pub fn to_mut_with<F>(this: &mut Self, f: F) -> &mut T
+    where
+        F: FnOnce(&T) -> T,
+    {
+        Bow::promote_in_place_with(this, f);
+        match *this {
+            Bow::Owned(ref mut t) => t,
+            Bow::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Consume the enclosed value and return it, making an owned duplicate
+    /// with [`MakeOwned`] if it is borrowed.
+    pub fn into_owned(this: Self) -> T
+    where
+        T: MakeOwned,
+    {
+        match this {
+            Bow::Owned(t) => t,
+            Bow::Borrowed(t) => t.make_owned(),
+        }
+    }
+
+    /// Get a mutable reference to the enclosed value, making an owned
+    /// duplicate with [`MakeOwned`] first if it is borrowed.
+    pub fn to_mut(this: &mut Self) -> &mut T
+    where
+        T: MakeOwned,
+    {
+        Bow::to_mut_with(this, MakeOwned::make_owned)
+    }
+
+    /// Make an owned duplicate of the enclosed value with [`MakeOwned`] and
+    /// modify it with `f`, leaving `self` untouched.
+    pub fn modify_cloned<F>(this: &Self, f: F) -> Bow<'static, T>
+    where
+        T: MakeOwned + 'static,
+        F: FnOnce(&mut T),
+    {
+        let mut t = (**this).make_owned();
+        f(&mut t);
+        Bow::Owned(t)
+    }
+
+    /// Detach from borrowed data, making an owned duplicate with
+    /// [`MakeOwned`] if the value is borrowed.
+    ///
+    /// The result satisfies `'static` bounds, e.g. to be moved into a
+    /// spawned thread.
+    ///
+    /// ```rust
+    /// extern crate boow;
+    /// use boow::Bow;
+    /// use std::thread;
+    ///
+    /// let name = "worker".to_owned();
+    /// let bow = Bow::into_send(Bow::Borrowed(&name));
+    /// let handle = thread::spawn(move || bow.len());
+    /// assert_eq!(handle.join().unwrap(), 6);
+    /// ```
+    pub fn into_send(this: Self) -> Bow<'static, T>
+    where
+        T: MakeOwned + 'static,
+    {
+        Bow::Owned(Bow::into_owned(this))
+    }
+
+    /// Convert into an [`Arc`] that can be cheaply shared, moving owned values
+    /// and making an owned duplicate of borrowed ones with [`MakeOwned`].
+    ///
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn freeze(this: Self) -> Arc<T>
+    where
+        T: MakeOwned,
+    {
+        Arc::new(Bow::into_owned(this))
+    }
+
+    /// Move the enclosed value to the heap, making an owned duplicate with
+    /// [`MakeOwned`] if it is borrowed.
+    pub fn into_box(this: Self) -> Box<T>
+    where
+        T: MakeOwned,
+    {
+        Box::new(Bow::into_owned(this))
+    }
+
+    /// Get a mutable reference to the enclosed value, trying to make an owned
+    /// duplicate with [`TryMakeOwned`] first if it is borrowed.
+    pub fn try_to_mut(this: &mut Self) -> Result<&mut T, T::Error>
+    where
+        T: TryMakeOwned,
+    {
+        if let Bow::Borrowed(t) = *this {
+            *this = Bow::Owned(t.try_make_owned()?);
+        }
+        match *this {
+            Bow::Owned(ref mut t) => Ok(t),
+            Bow::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Consume the enclosed value and return it, trying to make an owned
+    /// duplicate with [`TryMakeOwned`] if it is borrowed.
+    ///
+    /// Unlike [`try_into_owned`], this never fails for borrowed values
+    /// unless the duplication itself fails.
+    ///
+    /// [`try_into_owned`]: Bow::try_into_owned
+    pub fn try_clone_into_owned(this: Self) -> Result<T, T::Error>
+    where
+        T: TryMakeOwned,
+    {
+        match this {
+            Bow::Owned(t) => Ok(t),
+            Bow::Borrowed(t) => t.try_make_owned(),
+        }
+    }
+
+    /// Convert a `Bow<'a, T>` into a `Bow<'a, U>`, keeping the same variant.
+    ///
+    /// Owned values are converted with [`Into`], while borrowed values are
+    /// converted with [`AsRef`].
+    pub fn convert<U: 'a>(this: Self) -> Bow<'a, U>
+    where
+        T: Into<U> + AsRef<U>,
+    {
+        match this {
+            Bow::Owned(t) => Bow::Owned(t.into()),
+            Bow::Borrowed(t) => Bow::Borrowed(t.as_ref()),
+        }
+    }
+
+    /// Try to convert a `Bow<'a, T>` into a `Bow<'a, U>`, keeping the same
+    /// variant.
+    ///
+    /// Owned values are converted with [`TryInto`], while borrowed values are
+    /// converted with [`AsRef`] and cannot fail.
+    pub fn try_convert<U: 'a>(this: Self) -> Result<Bow<'a, U>, T::Error>
+    where
+        T: TryInto<U> + AsRef<U>,
+    {
+        match this {
+            Bow::Owned(t) => t.try_into().map(Bow::Owned),
+            Bow::Borrowed(t) => Ok(Bow::Borrowed(t.as_ref())),
+        }
+    }
+}
+
+impl<'a, A: 'a, B: 'a> Bow<'a, (A, B)> {
+    /// Split a [`Bow`] of a pair into a pair of [`Bow`]s.
+    ///
+    /// An owned pair is destructured into two owned values, while a borrowed
+    /// pair is projected into two borrowed references.
+    pub fn split(this: Self) -> (Bow<'a, A>, Bow<'a, B>) {
+        match this {
+            Bow::Owned((a, b)) => (Bow::Owned(a), Bow::Owned(b)),
+            Bow::Borrowed((a, b)) => (Bow::Borrowed(a), Bow::Borrowed(b)),
+        }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Bow<'a, [T; N]> {
+    /// Split a [`Bow`] of an array into an array of [`Bow`]s.
+    ///
+    /// The elements of an owned array are moved out individually, while a
+    /// borrowed array is projected into per-element borrowed references.
+    pub fn each(this: Self) -> [Bow<'a, T>; N] {
+        match this {
+            Bow::Owned(array) => array.map(Bow::Owned),
+            Bow::Borrowed(array) => array.each_ref().map(Bow::Borrowed),
+        }
+    }
+}
+
+/// Project a [`Bow`] of a struct into a struct of [`Bow`]s, one per field.
+///
+/// Fields of an owned struct are moved out individually, while a borrowed
+/// struct is projected into per-field borrowed references. Use
+/// [`Bow::project`] to call it.
+///
+/// With the `derive` feature, this can be derived with
+/// `#[derive(ProjectBow)]`, which generates a `<Name>Projection` struct
+/// with the same fields wrapped in [`Bow`]s. The deriving struct must not
+/// implement [`Drop`].
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # fn main() {
+/// use boow::{Bow, ProjectBow};
+///
+/// #[derive(ProjectBow)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+/// }
+///
+/// let config = Config {
+///     name: "boow".to_owned(),
+///     retries: 3,
+/// };
+/// let projection = Bow::project(Bow::Borrowed(&config));
+/// assert!(Bow::is_borrowed(&projection.name));
+///
+/// let projection = Bow::project(Bow::Owned(config));
+/// let name: String = Bow::extract(projection.name).unwrap();
+/// assert_eq!(name, "boow");
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # fn main() {}
+/// ```
+pub trait ProjectBow<'a>: Sized + 'a {
+    /// Struct of [`Bow`]s returned by [`project`].
+    ///
+    /// [`project`]: ProjectBow::project
+    type Projection;
+
+    /// Project `bow` into a struct of [`Bow`]s.
+    fn project(bow: Bow<'a, Self>) -> Self::Projection;
+}
+
+impl<'a, T: 'a> Bow<'a, T>
+where
+    T: ProjectBow<'a>,
+{
+    /// Project a [`Bow`] of a struct into a struct of [`Bow`]s with
+    /// [`ProjectBow`].
+    pub fn project(this: Self) -> T::Projection {
+        T::project(this)
+    }
+}
+
+/// Make an owned duplicate of a value.
+///
+/// This is implemented for all types implementing [`Clone`], but can also be
+/// implemented for types which do not want to expose a public [`Clone`]
+/// implementation. Such types can then use [`Bow::into_owned`] and
+/// [`Bow::to_mut`].
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, MakeOwned};
+///
+/// struct Handle {
+///     id: u32,
+/// }
+///
+/// impl MakeOwned for Handle {
+///     fn make_owned(&self) -> Self {
+///         Handle { id: self.id + 1 }
+///     }
+/// }
+///
+/// let handle = Handle { id: 0 };
+/// let mut bow = Bow::Borrowed(&handle);
+/// Bow::to_mut(&mut bow).id += 1;
+/// assert_eq!(bow.id, 2);
+/// ```
+pub trait MakeOwned {
+    /// Make an owned duplicate of `self`.
+    fn make_owned(&self) -> Self;
+}
+
+impl<T: Clone> MakeOwned for T {
+    fn make_owned(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Try to make an owned duplicate of a value.
+///
+/// Fallible counterpart of [`MakeOwned`], for types such as resource handles
+/// whose duplication may fail (e.g. [`File::try_clone`]).
+///
+/// [`File::try_clone`]: https://doc.rust-lang.org/std/fs/struct.File.html#method.try_clone
+pub trait TryMakeOwned: Sized {
+    /// The type returned in the event of a duplication error.
+    type Error;
+
+    /// Try to make an owned duplicate of `self`.
+    fn try_make_owned(&self) -> Result<Self, Self::Error>;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_try_make_owned_with_try_clone {
+    ($($t:ty),*) => {
+        $(
+            impl TryMakeOwned for $t {
+                type Error = io::Error;
+
+                fn try_make_owned(&self) -> io::Result<Self> {
+                    self.try_clone()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl_try_make_owned_with_try_clone!(fs::File, net::TcpListener, net::TcpStream, net::UdpSocket);
+
+/// Error returned when trying to mutate a [`Bow`] that is not owned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotOwnedError;
+
+impl fmt::Display for NotOwnedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("value is borrowed, cannot mutate")
+    }
+}
+
+impl Error for NotOwnedError {}
+
+/// Error returned when trying to take ownership of a [`Bow`] that is not
+/// owned.
+///
+/// The borrowed reference can be recovered with [`into_borrowed`].
+///
+/// [`into_borrowed`]: IntoOwnedError::into_borrowed
+pub struct IntoOwnedError<'a, T: 'a> {
+    borrowed: &'a T,
+}
+
+impl<'a, T: 'a> IntoOwnedError<'a, T> {
+    /// Get back the borrowed reference.
+    pub fn into_borrowed(self) -> &'a T {
+        self.borrowed
+    }
+}
+
+impl<'a, T: 'a> Clone for IntoOwnedError<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a> Copy for IntoOwnedError<'a, T> {}
+
+impl<'a, T: 'a> fmt::Debug for IntoOwnedError<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntoOwnedError").finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: 'a> fmt::Display for IntoOwnedError<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("value is borrowed, cannot take ownership")
+    }
 }
 
+impl<'a, T: 'a> Error for IntoOwnedError<'a, T> {}
+
+#[cfg(not(feature = "nightly-const"))]
 impl<'a, T: 'a> Eq for Bow<'a, T> where T: Eq {}
 
+#[cfg(not(feature = "nightly-const"))]
 impl<'a, T: 'a> Ord for Bow<'a, T>
 where
     T: Ord,
@@ -121,6 +984,7 @@ where
     }
 }
 
+#[cfg(not(feature = "nightly-const"))]
 impl<'a, T: 'a> PartialEq for Bow<'a, T>
 where
     T: PartialEq,
@@ -130,6 +994,7 @@ where
     }
 }
 
+#[cfg(not(feature = "nightly-const"))]
 impl<'a, T: 'a> PartialOrd for Bow<'a, T>
 where
     T: PartialOrd,
@@ -157,6 +1022,27 @@ where
     }
 }
 
+impl<'a, T: 'a> Clone for Bow<'a, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            Bow::Owned(ref t) => Bow::Owned(t.clone()),
+            Bow::Borrowed(t) => Bow::Borrowed(t),
+        }
+    }
+
+    /// Reuse the resources of an owned value, e.g. the capacity of a
+    /// `String`, if both values are owned.
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (Bow::Owned(t), Bow::Owned(s)) => t.clone_from(s),
+            (this, source) => *this = source.clone(),
+        }
+    }
+}
+
 impl<'a, T: 'a> Default for Bow<'a, T>
 where
     T: Default,
@@ -175,8 +1061,108 @@ where
     }
 }
 
+impl<'a, T: 'a> FromStr for Bow<'a, T>
+where
+    T: FromStr,
+{
+    type Err = T::Err;
+
+    /// Parse a value into the [`Owned`] variant.
+    ///
+    /// [`Owned`]: Bow::Owned
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(Bow::Owned)
+    }
+}
+
+impl<'a, T: 'a> From<&'a T> for Bow<'a, T> {
+    fn from(t: &'a T) -> Self {
+        Bow::Borrowed(t)
+    }
+}
+
+/// Move the boxed value out of the heap into the [`Owned`] variant.
+///
+/// [`Owned`]: Bow::Owned
+impl<'a, T: 'a> From<Box<T>> for Bow<'a, T> {
+    fn from(t: Box<T>) -> Self {
+        Bow::Owned(*t)
+    }
+}
+
+/// Demote a mutable borrow to a shared one.
+impl<'a, T: 'a> From<&'a mut T> for Bow<'a, T> {
+    fn from(t: &'a mut T) -> Self {
+        Bow::Borrowed(t)
+    }
+}
+
+macro_rules! impl_sum_product {
+    ($($t:ty)*) => {
+        $(
+            impl<'a> Sum<Bow<'a, $t>> for $t {
+                fn sum<I: Iterator<Item = Bow<'a, $t>>>(iter: I) -> Self {
+                    iter.map(|bow| *bow).sum()
+                }
+            }
+
+            impl<'a, 'b> Sum<&'b Bow<'a, $t>> for $t {
+                fn sum<I: Iterator<Item = &'b Bow<'a, $t>>>(iter: I) -> Self {
+                    iter.map(|bow| **bow).sum()
+                }
+            }
+
+            impl<'a> Product<Bow<'a, $t>> for $t {
+                fn product<I: Iterator<Item = Bow<'a, $t>>>(iter: I) -> Self {
+                    iter.map(|bow| *bow).product()
+                }
+            }
+
+            impl<'a, 'b> Product<&'b Bow<'a, $t>> for $t {
+                fn product<I: Iterator<Item = &'b Bow<'a, $t>>>(iter: I) -> Self {
+                    iter.map(|bow| **bow).product()
+                }
+            }
+        )*
+    };
+}
+
+impl_sum_product! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64 }
+
 impl<'a, T: 'a> AsRef<T> for Bow<'a, T> {
     fn as_ref(&self) -> &T {
         self
     }
 }
+
+/// Iterate an owned iterator directly. A borrowed iterator is first
+/// duplicated with [`MakeOwned`] on the first call to [`next`], leaving the
+/// borrowed one untouched.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::Bow;
+///
+/// let source = [1, 2, 3].iter();
+/// let mut bow = Bow::Borrowed(&source);
+/// assert_eq!(bow.next(), Some(&1));
+/// assert!(Bow::is_owned(&bow));
+/// assert_eq!(bow.sum::<i32>(), 5);
+/// assert_eq!(source.len(), 3);
+/// ```
+///
+/// [`next`]: Iterator::next
+impl<'a, I> Iterator for Bow<'a, I>
+where
+    I: Iterator + MakeOwned + 'a,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        Bow::to_mut(self).next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
@@ -0,0 +1,87 @@
+//! Macros shared by the members of the Bow family.
+
+/// Implement traits by delegating to the dereferenced value, so that all
+/// smart pointers of the family compare, hash and format like the value
+/// they enclose.
+///
+/// ```text
+/// impl_deref_traits! {
+///     ['a, T: 'a] BowOrBox<'a, T> => T:
+///         PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+/// }
+/// ```
+macro_rules! impl_deref_traits {
+    ($params:tt $ty:ty => $target:ty: $($tr:ident),+ $(,)?) => {
+        $(impl_deref_traits!(@$tr $params $ty => $target);)+
+    };
+    (@PartialEq [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> PartialEq for $ty
+        where
+            $target: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                PartialEq::eq(&**self, &**other)
+            }
+        }
+    };
+    (@Eq [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> Eq for $ty where $target: Eq {}
+    };
+    (@PartialOrd [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> PartialOrd for $ty
+        where
+            $target: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<$crate::Ordering> {
+                PartialOrd::partial_cmp(&**self, &**other)
+            }
+        }
+    };
+    (@Ord [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> Ord for $ty
+        where
+            $target: Ord,
+        {
+            fn cmp(&self, other: &Self) -> $crate::Ordering {
+                Ord::cmp(&**self, &**other)
+            }
+        }
+    };
+    (@Hash [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> $crate::Hash for $ty
+        where
+            $target: $crate::Hash,
+        {
+            fn hash<H: $crate::Hasher>(&self, state: &mut H) {
+                $crate::Hash::hash(&**self, state)
+            }
+        }
+    };
+    (@Debug [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> $crate::fmt::Debug for $ty
+        where
+            $target: $crate::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $crate::fmt::Formatter) -> $crate::fmt::Result {
+                $crate::fmt::Debug::fmt(&**self, f)
+            }
+        }
+    };
+    (@Display [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> $crate::fmt::Display for $ty
+        where
+            $target: $crate::fmt::Display,
+        {
+            fn fmt(&self, f: &mut $crate::fmt::Formatter) -> $crate::fmt::Result {
+                $crate::fmt::Display::fmt(&**self, f)
+            }
+        }
+    };
+    (@AsRef [$($params:tt)*] $ty:ty => $target:ty) => {
+        impl<$($params)*> AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                self
+            }
+        }
+    };
+}
@@ -0,0 +1,72 @@
+//! Map lookups returning [`Bow`]s.
+
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::borrow::Borrow;
+        use std::collections::{BTreeMap, HashMap};
+        use std::hash::{BuildHasher, Hash};
+    } else {
+        use alloc::borrow::Borrow;
+        use alloc::collections::BTreeMap;
+    }
+}
+
+use Bow;
+
+/// Extension methods for maps, borrowing existing entries and owning
+/// computed fallbacks.
+///
+/// Implemented for [`BTreeMap`], and for [`HashMap`] with the `std` feature.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, MapBowExt};
+/// use std::collections::HashMap;
+///
+/// let mut names = HashMap::new();
+/// names.insert(1, "one".to_owned());
+///
+/// let one = names.get_bow_or_else(&1, || "unknown".to_owned());
+/// let two = names.get_bow_or_else(&2, || "unknown".to_owned());
+/// assert!(Bow::is_borrowed(&one));
+/// assert!(Bow::is_owned(&two));
+/// assert_eq!(*two, "unknown");
+/// ```
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+pub trait MapBowExt<Q: ?Sized, V> {
+    /// Borrow the value corresponding to the key, if any.
+    fn get_bow(&self, k: &Q) -> Option<Bow<'_, V>>;
+
+    /// Borrow the value corresponding to the key, or compute an owned one
+    /// with `f` if there is none. The map is left untouched.
+    fn get_bow_or_else<F>(&self, k: &Q, f: F) -> Bow<'_, V>
+    where
+        F: FnOnce() -> V,
+    {
+        self.get_bow(k).unwrap_or_else(|| Bow::Owned(f()))
+    }
+}
+
+impl<K, V, Q> MapBowExt<Q, V> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: ?Sized + Ord,
+{
+    fn get_bow(&self, k: &Q) -> Option<Bow<'_, V>> {
+        self.get(k).map(Bow::Borrowed)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S, Q> MapBowExt<Q, V> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: ?Sized + Hash + Eq,
+    S: BuildHasher,
+{
+    fn get_bow(&self, k: &Q) -> Option<Bow<'_, V>> {
+        self.get(k).map(Bow::Borrowed)
+    }
+}
@@ -0,0 +1,48 @@
+//! `const` trait implementations for [`Bow`], enabled with the
+//! `nightly-const` feature.
+//!
+//! They replace the regular implementations so that [`Bow`] values can be
+//! dereferenced and compared in `const` contexts. The calling crate must
+//! enable the `const_trait_impl`, `const_convert` and `const_cmp` nightly
+//! features as well.
+
+use super::{Bow, Deref, Ordering};
+
+impl<'a, T: 'a> const Deref for Bow<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match *self {
+            Bow::Owned(ref t) => t,
+            Bow::Borrowed(t) => t,
+        }
+    }
+}
+
+impl<'a, T: 'a> const Eq for Bow<'a, T> where T: [const] Eq {}
+
+impl<'a, T: 'a> const Ord for Bow<'a, T>
+where
+    T: [const] Ord,
+{
+    fn cmp(&self, other: &Bow<'a, T>) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<'a, T: 'a> const PartialEq for Bow<'a, T>
+where
+    T: [const] PartialEq,
+{
+    fn eq(&self, other: &Bow<'a, T>) -> bool {
+        PartialEq::eq(&**self, &**other)
+    }
+}
+
+impl<'a, T: 'a> const PartialOrd for Bow<'a, T>
+where
+    T: [const] PartialOrd,
+{
+    fn partial_cmp(&self, other: &Bow<'a, T>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
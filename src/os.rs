@@ -0,0 +1,72 @@
+//! Forwarding of the OS handle traits, so that a [`Bow`] of a file, socket,
+//! etc. can be passed to APIs expecting a raw handle.
+
+#[cfg(unix)]
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
+#[cfg(windows)]
+use std::os::windows::io::{
+    AsHandle, AsRawHandle, AsRawSocket, AsSocket, BorrowedHandle, BorrowedSocket, RawHandle,
+    RawSocket,
+};
+
+use Bow;
+
+#[cfg(unix)]
+impl<'a, T: 'a> AsFd for Bow<'a, T>
+where
+    T: AsFd,
+{
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        (**self).as_fd()
+    }
+}
+
+#[cfg(unix)]
+impl<'a, T: 'a> AsRawFd for Bow<'a, T>
+where
+    T: AsRawFd,
+{
+    fn as_raw_fd(&self) -> RawFd {
+        (**self).as_raw_fd()
+    }
+}
+
+#[cfg(windows)]
+impl<'a, T: 'a> AsHandle for Bow<'a, T>
+where
+    T: AsHandle,
+{
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        (**self).as_handle()
+    }
+}
+
+#[cfg(windows)]
+impl<'a, T: 'a> AsRawHandle for Bow<'a, T>
+where
+    T: AsRawHandle,
+{
+    fn as_raw_handle(&self) -> RawHandle {
+        (**self).as_raw_handle()
+    }
+}
+
+#[cfg(windows)]
+impl<'a, T: 'a> AsSocket for Bow<'a, T>
+where
+    T: AsSocket,
+{
+    fn as_socket(&self) -> BorrowedSocket<'_> {
+        (**self).as_socket()
+    }
+}
+
+#[cfg(windows)]
+impl<'a, T: 'a> AsRawSocket for Bow<'a, T>
+where
+    T: AsRawSocket,
+{
+    fn as_raw_socket(&self) -> RawSocket {
+        (**self).as_raw_socket()
+    }
+}
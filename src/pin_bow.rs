@@ -0,0 +1,95 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::ops::Deref;
+        use std::pin::Pin;
+    } else {
+        use alloc::boxed::Box;
+        use core::ops::Deref;
+        use core::pin::Pin;
+    }
+}
+
+/// Borrow-Or-oWned smart pointer to a pinned value.
+///
+/// Owned values are pinned on the heap, so that self-referential or
+/// intrusive types (e.g. futures) can be held. Like [`BowDyn`], `T` may be a
+/// trait object such as `dyn Future<Output = u8>`.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::PinBow;
+/// use std::pin::Pin;
+///
+/// let pinned = Box::pin(42);
+/// let values = [PinBow::pin(1), PinBow::Borrowed(pinned.as_ref())];
+/// let pins: Vec<Pin<&i32>> = values.iter().map(PinBow::as_pin).collect();
+/// assert_eq!(*pins[1], 42);
+/// ```
+///
+/// [`BowDyn`]: crate::BowDyn
+pub enum PinBow<'a, T: ?Sized + 'a> {
+    Owned(Pin<Box<T>>),
+    Borrowed(Pin<&'a T>),
+}
+
+impl<'a, T: 'a> PinBow<'a, T> {
+    /// Pin an owned value on the heap.
+    pub fn pin(t: T) -> Self {
+        PinBow::Owned(Box::pin(t))
+    }
+}
+
+impl<'a, T: ?Sized + 'a> PinBow<'a, T> {
+    /// Get a pinned reference to the enclosed value.
+    pub fn as_pin(this: &Self) -> Pin<&T> {
+        match *this {
+            PinBow::Owned(ref t) => t.as_ref(),
+            PinBow::Borrowed(t) => t,
+        }
+    }
+
+    /// Get a pinned mutable reference to the enclosed value, e.g. to poll a
+    /// future. Return [`None`] if the value is not owned.
+    pub fn as_pin_mut(this: &mut Self) -> Option<Pin<&mut T>> {
+        match *this {
+            PinBow::Owned(ref mut t) => Some(t.as_mut()),
+            PinBow::Borrowed(_) => None,
+        }
+    }
+}
+
+impl<'a, T: ?Sized + 'a> Deref for PinBow<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match *self {
+            PinBow::Owned(ref t) => t,
+            PinBow::Borrowed(t) => t.get_ref(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized + 'a> From<Pin<Box<T>>> for PinBow<'a, T> {
+    fn from(t: Pin<Box<T>>) -> Self {
+        PinBow::Owned(t)
+    }
+}
+
+impl<'a, T: ?Sized + 'a> From<Pin<&'a T>> for PinBow<'a, T> {
+    fn from(t: Pin<&'a T>) -> Self {
+        PinBow::Borrowed(t)
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: ?Sized + 'a] PinBow<'a, T> => T:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+}
+
+impl<'a, T: 'a> Default for PinBow<'a, T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        PinBow::pin(T::default())
+    }
+}
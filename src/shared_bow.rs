@@ -0,0 +1,162 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        #[cfg(feature = "borrow")]
+        use std::borrow::Borrow;
+        use std::ops::Deref;
+        use std::sync::Arc;
+    } else {
+        #[cfg(feature = "borrow")]
+        use alloc::borrow::Borrow;
+        use alloc::sync::Arc;
+        use core::ops::Deref;
+    }
+}
+
+use {Bow, MakeOwned};
+
+/// Borrow-Or-oWned smart pointer with an additional variant for shared
+/// ownership.
+///
+/// Like [`Bow`], but the enclosed value can also be owned through a smart
+/// pointer `P` dereferencing to it, by default an [`Arc`]. Any other pointer,
+/// e.g. an [`Rc`] or a lock guard, can be used instead, without boxing.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::SharedBow;
+/// use std::sync::Arc;
+///
+/// let config = Arc::new("shared".to_owned());
+/// let local = "borrowed".to_owned();
+/// let values: Vec<SharedBow<String>> = vec![
+///     SharedBow::Shared(config.clone()),
+///     SharedBow::Borrowed(&local),
+///     SharedBow::Owned("owned".to_owned()),
+/// ];
+/// let joined: String = values.iter().map(|value| value.as_str()).collect();
+/// assert_eq!(joined, "sharedborrowedowned");
+/// assert_eq!(Arc::strong_count(&config), 2);
+/// ```
+///
+/// [`Bow`]: crate::Bow
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+pub enum SharedBow<'a, T: 'a, P = Arc<T>> {
+    Owned(T),
+    Borrowed(&'a T),
+    Shared(P),
+}
+
+#[cfg(feature = "borrow")]
+impl<'a, T: 'a, P: Deref<Target = T>> Borrow<T> for SharedBow<'a, T, P> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<'a, T: 'a, P: Deref<Target = T>> Deref for SharedBow<'a, T, P> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match *self {
+            SharedBow::Owned(ref t) => t,
+            SharedBow::Borrowed(t) => t,
+            SharedBow::Shared(ref p) => p,
+        }
+    }
+}
+
+impl<'a, T: 'a, P: Deref<Target = T>> SharedBow<'a, T, P> {
+    /// Return whether the value is owned through the shared pointer.
+    pub fn is_shared(this: &Self) -> bool {
+        matches!(*this, SharedBow::Shared(_))
+    }
+
+    /// Get a mutable reference to the enclosed value. Return [`None`] if the
+    /// value is not owned.
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut T> {
+        match *this {
+            SharedBow::Owned(ref mut t) => Some(t),
+            SharedBow::Borrowed(_) | SharedBow::Shared(_) => None,
+        }
+    }
+
+    /// Consume the enclosed value and return it if it is owned.
+    pub fn extract(this: Self) -> Option<T> {
+        match this {
+            SharedBow::Owned(t) => Some(t),
+            SharedBow::Borrowed(_) | SharedBow::Shared(_) => None,
+        }
+    }
+
+    /// Consume the enclosed value and return it, making an owned duplicate
+    /// with [`MakeOwned`] if it is borrowed or shared.
+    pub fn into_owned(this: Self) -> T
+    where
+        T: MakeOwned,
+    {
+        match this {
+            SharedBow::Owned(t) => t,
+            SharedBow::Borrowed(t) => t.make_owned(),
+            SharedBow::Shared(p) => (*p).make_owned(),
+        }
+    }
+
+    /// Move an owned value into a new shared pointer, e.g. before handing
+    /// out clones of the [`SharedBow`]. Borrowed and shared values are
+    /// left untouched.
+    pub fn share(this: Self) -> Self
+    where
+        P: From<T>,
+    {
+        match this {
+            SharedBow::Owned(t) => SharedBow::Shared(P::from(t)),
+            bow => bow,
+        }
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: 'a, P: Deref<Target = T>] SharedBow<'a, T, P> => T:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+}
+
+impl<'a, T: 'a, P> Clone for SharedBow<'a, T, P>
+where
+    T: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            SharedBow::Owned(ref t) => SharedBow::Owned(t.clone()),
+            SharedBow::Borrowed(t) => SharedBow::Borrowed(t),
+            SharedBow::Shared(ref p) => SharedBow::Shared(p.clone()),
+        }
+    }
+
+    /// Reuse the resources of an owned value, e.g. the capacity of a
+    /// `String`, if both values are owned.
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (SharedBow::Owned(t), SharedBow::Owned(s)) => t.clone_from(s),
+            (this, source) => *this = source.clone(),
+        }
+    }
+}
+
+impl<'a, T: 'a, P: Deref<Target = T>> Default for SharedBow<'a, T, P>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        SharedBow::Owned(T::default())
+    }
+}
+
+impl<'a, T: 'a, P: Deref<Target = T>> From<Bow<'a, T>> for SharedBow<'a, T, P> {
+    fn from(bow: Bow<'a, T>) -> Self {
+        match bow {
+            Bow::Owned(t) => SharedBow::Owned(t),
+            Bow::Borrowed(t) => SharedBow::Borrowed(t),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        #[cfg(feature = "borrow")]
+        use std::borrow::Borrow;
+        use std::marker::PhantomData;
+        use std::mem::{self, ManuallyDrop, MaybeUninit};
+        use std::ops::Deref;
+        use std::ptr;
+    } else {
+        #[cfg(feature = "borrow")]
+        use alloc::borrow::Borrow;
+        use alloc::boxed::Box;
+        use core::marker::PhantomData;
+        use core::mem::{self, ManuallyDrop, MaybeUninit};
+        use core::ops::Deref;
+        use core::ptr;
+    }
+}
+
+use {Bow, MakeOwned};
+
+/// Borrow-Or-oWned smart pointer storing small owned values inline and
+/// spilling large ones to the heap.
+///
+/// Owned values fitting in `N` machine words (and not more aligned than a
+/// machine word) are stored inline. Bigger owned values are boxed. This way,
+/// the size of a [`SmallBow`] never exceeds the larger of `N` words and a
+/// pointer, plus a discriminant, whatever the size of `T`. With `N = 0`, only
+/// zero-sized values are stored inline and a [`SmallBow`] is as big as a
+/// pointer and a discriminant.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::SmallBow;
+///
+/// let small: SmallBow<u64, 1> = SmallBow::owned(42);
+/// assert!(SmallBow::is_inline(&small));
+///
+/// let big: SmallBow<[u64; 4], 1> = SmallBow::owned([0; 4]);
+/// assert!(!SmallBow::is_inline(&big));
+/// ```
+pub struct SmallBow<'a, T: 'a, const N: usize> {
+    repr: Repr<'a, T, N>,
+}
+
+enum Repr<'a, T: 'a, const N: usize> {
+    Borrowed(&'a T),
+    // Holds an initialized `T` as long as the `SmallBow` is alive.
+    Inline(MaybeUninit<[usize; N]>, PhantomData<T>),
+    Boxed(Box<T>),
+}
+
+impl<'a, T: 'a, const N: usize> SmallBow<'a, T, N> {
+    /// Whether a value of type `T` would be stored inline.
+    pub const FITS_INLINE: bool = mem::size_of::<T>() <= mem::size_of::<[usize; N]>()
+        && mem::align_of::<T>() <= mem::align_of::<usize>();
+
+    /// Wrap a borrowed value.
+    pub fn borrowed(t: &'a T) -> Self {
+        SmallBow {
+            repr: Repr::Borrowed(t),
+        }
+    }
+
+    /// Wrap an owned value, storing it inline if it is small enough or
+    /// boxing it otherwise.
+    pub fn owned(t: T) -> Self {
+        let repr = if Self::FITS_INLINE {
+            let mut buf = MaybeUninit::<[usize; N]>::uninit();
+            // SAFETY: `FITS_INLINE` guarantees that the buffer is big enough
+            // and sufficiently aligned to hold a `T`.
+            unsafe { ptr::write(buf.as_mut_ptr() as *mut T, t) };
+            Repr::Inline(buf, PhantomData)
+        } else {
+            Repr::Boxed(Box::new(t))
+        };
+        SmallBow { repr }
+    }
+
+    /// Return whether the value is owned.
+    pub fn is_owned(this: &Self) -> bool {
+        !SmallBow::is_borrowed(this)
+    }
+
+    /// Return whether the value is borrowed.
+    pub fn is_borrowed(this: &Self) -> bool {
+        matches!(this.repr, Repr::Borrowed(_))
+    }
+
+    /// Return whether the value is owned and stored inline.
+    pub fn is_inline(this: &Self) -> bool {
+        matches!(this.repr, Repr::Inline(..))
+    }
+
+    /// Get a mutable reference to the enclosed value. Return [`None`] if the
+    /// value is not owned.
+    pub fn borrow_mut(this: &mut Self) -> Option<&mut T> {
+        match this.repr {
+            Repr::Borrowed(_) => None,
+            // SAFETY: The buffer holds an initialized `T`.
+            Repr::Inline(ref mut buf, _) => Some(unsafe { &mut *(buf.as_mut_ptr() as *mut T) }),
+            Repr::Boxed(ref mut t) => Some(t),
+        }
+    }
+
+    /// Consume the enclosed value and return it if it is owned.
+    pub fn extract(this: Self) -> Option<T> {
+        let this = ManuallyDrop::new(this);
+        // SAFETY: `this` is never dropped, so moving the owned value out of it
+        // does not cause a double drop.
+        unsafe {
+            match this.repr {
+                Repr::Borrowed(_) => None,
+                Repr::Inline(ref buf, _) => Some(ptr::read(buf.as_ptr() as *const T)),
+                Repr::Boxed(ref t) => Some(*ptr::read(t)),
+            }
+        }
+    }
+
+    /// Consume the enclosed value and return it, making an owned duplicate
+    /// with [`MakeOwned`] if it is borrowed.
+    pub fn into_owned(this: Self) -> T
+    where
+        T: MakeOwned,
+    {
+        match this.repr {
+            Repr::Borrowed(t) => t.make_owned(),
+            _ => SmallBow::extract(this).unwrap(),
+        }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Drop for SmallBow<'a, T, N> {
+    fn drop(&mut self) {
+        if let Repr::Inline(ref mut buf, _) = self.repr {
+            // SAFETY: The buffer holds an initialized `T`, which is dropped
+            // exactly once here.
+            unsafe { ptr::drop_in_place(buf.as_mut_ptr() as *mut T) }
+        }
+    }
+}
+
+#[cfg(feature = "borrow")]
+impl<'a, T: 'a, const N: usize> Borrow<T> for SmallBow<'a, T, N> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Deref for SmallBow<'a, T, N> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match self.repr {
+            Repr::Borrowed(t) => t,
+            // SAFETY: The buffer holds an initialized `T`.
+            Repr::Inline(ref buf, _) => unsafe { &*(buf.as_ptr() as *const T) },
+            Repr::Boxed(ref t) => t,
+        }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Clone for SmallBow<'a, T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match self.repr {
+            Repr::Borrowed(t) => SmallBow::borrowed(t),
+            _ => SmallBow::owned(T::clone(self)),
+        }
+    }
+
+    /// Reuse the resources of an owned value, e.g. the capacity of a
+    /// `String`, if both values are owned.
+    fn clone_from(&mut self, source: &Self) {
+        match (SmallBow::borrow_mut(self), SmallBow::is_owned(source)) {
+            (Some(t), true) => t.clone_from(source),
+            _ => *self = source.clone(),
+        }
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: 'a, const N: usize] SmallBow<'a, T, N> => T:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+}
+
+impl<'a, T: 'a, const N: usize> Default for SmallBow<'a, T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        SmallBow::owned(T::default())
+    }
+}
+
+impl<'a, T: 'a, const N: usize> From<Bow<'a, T>> for SmallBow<'a, T, N> {
+    fn from(bow: Bow<'a, T>) -> Self {
+        match bow {
+            Bow::Owned(t) => SmallBow::owned(t),
+            Bow::Borrowed(t) => SmallBow::borrowed(t),
+        }
+    }
+}
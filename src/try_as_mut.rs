@@ -0,0 +1,118 @@
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::ops::Deref;
+        use std::rc::Rc;
+        use std::sync::Arc;
+    } else {
+        use alloc::rc::Rc;
+        use alloc::sync::Arc;
+        use core::ops::Deref;
+    }
+}
+
+use typestate::{Owned, TypedBow};
+use {Bow, BowDyn, BowMutSlice, BowOption, BowOrBox, GenBow, GuardBow, SharedBow, SmallBow};
+
+/// Fallible mutable access, e.g. to a value which may be borrowed or shared.
+///
+/// Lets generic code mutate a value whenever it is allowed to, whatever the
+/// smart pointer holding it.
+///
+/// ```rust
+/// extern crate boow;
+/// use boow::{Bow, TryAsMut};
+/// use std::rc::Rc;
+///
+/// fn normalize<P: TryAsMut<String>>(name: &mut P) -> bool {
+///     name.try_with_mut(|name| name.make_ascii_lowercase())
+/// }
+///
+/// let original = "Boow".to_owned();
+/// assert!(!normalize(&mut Bow::Borrowed(&original)));
+/// assert!(normalize(&mut Bow::Owned(original.clone())));
+///
+/// let mut shared = Rc::new(original);
+/// assert!(normalize(&mut shared));
+/// let _clone = shared.clone();
+/// assert!(!normalize(&mut shared));
+/// ```
+pub trait TryAsMut<T: ?Sized> {
+    /// Get a mutable reference to the value, if allowed.
+    fn try_as_mut(&mut self) -> Option<&mut T>;
+
+    /// Call `f` with a mutable reference to the value, if allowed. Return
+    /// whether `f` was called.
+    fn try_with_mut<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        match self.try_as_mut() {
+            Some(t) => {
+                f(t);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a, T: ?Sized + 'a> TryAsMut<T> for &'a mut T {
+    fn try_as_mut(&mut self) -> Option<&mut T> {
+        Some(self)
+    }
+}
+
+impl<T: ?Sized> TryAsMut<T> for Rc<T> {
+    fn try_as_mut(&mut self) -> Option<&mut T> {
+        Rc::get_mut(self)
+    }
+}
+
+impl<T: ?Sized> TryAsMut<T> for Arc<T> {
+    fn try_as_mut(&mut self) -> Option<&mut T> {
+        Arc::get_mut(self)
+    }
+}
+
+macro_rules! impl_try_as_mut_with_borrow_mut {
+    ($([$($params:tt)*] $ty:ty => $target:ty;)*) => {
+        $(
+            impl<$($params)*> TryAsMut<$target> for $ty {
+                fn try_as_mut(&mut self) -> Option<&mut $target> {
+                    <$ty>::borrow_mut(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_as_mut_with_borrow_mut! {
+    ['a, T: 'a] Bow<'a, T> => T;
+    ['a, T: 'a] BowOrBox<'a, T> => T;
+    ['a, T: 'a] GuardBow<'a, T> => T;
+    ['a, T: 'a, P: Deref<Target = T>] SharedBow<'a, T, P> => T;
+    ['a, T: 'a, const N: usize] SmallBow<'a, T, N> => T;
+    ['a, B: ?Sized + 'a, O] GenBow<'a, B, O> => O;
+    ['a, F: ?Sized + 'a] BowDyn<'a, F> => F;
+}
+
+impl<'a, T: 'a> TryAsMut<T> for BowOption<'a, T> {
+    fn try_as_mut(&mut self) -> Option<&mut T> {
+        match *self {
+            BowOption::Owned(ref mut t) => Some(t),
+            BowOption::None | BowOption::Borrowed(_) => None,
+        }
+    }
+}
+
+impl<'a, T: 'a> TryAsMut<[T]> for BowMutSlice<'a, T> {
+    fn try_as_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+}
+
+impl<'a, T: 'a> TryAsMut<T> for TypedBow<'a, T, Owned> {
+    fn try_as_mut(&mut self) -> Option<&mut T> {
+        Some(self)
+    }
+}
@@ -0,0 +1,173 @@
+//! Borrow-Or-oWned smart pointer whose ownership is known at compile time.
+//!
+//! A [`TypedBow`] is either a `TypedBow<T, Owned>` or a
+//! `TypedBow<T, Borrowed>`. APIs which statically know whether they hold an
+//! owned value get infallible mutation, while still handing out a dynamic
+//! [`Bow`] where the ownership is only known at runtime.
+//!
+//! ```rust
+//! extern crate boow;
+//! use boow::typestate::{Borrowed, Owned, TypedBow};
+//! use boow::Bow;
+//!
+//! let default = vec![1, 2];
+//! let borrowed: TypedBow<Vec<i32>, Borrowed> = TypedBow::borrowed(&default);
+//! let mut owned: TypedBow<Vec<i32>, Owned> = TypedBow::into_owned(borrowed);
+//! owned.push(3);
+//! assert_eq!(*owned, [1, 2, 3]);
+//!
+//! let bow: Bow<Vec<i32>> = owned.into();
+//! assert!(Bow::is_owned(&bow));
+//! ```
+//!
+//! [`Bow`]: crate::Bow
+
+cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::ops::{Deref, DerefMut};
+    } else {
+        use core::ops::{Deref, DerefMut};
+    }
+}
+
+use {Bow, MakeOwned};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Ownership state of a [`TypedBow`], either [`Owned`] or [`Borrowed`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait State<'a, T: 'a>: sealed::Sealed {
+    /// How the value is stored in this state.
+    type Storage;
+
+    #[doc(hidden)]
+    fn get(storage: &Self::Storage) -> &T;
+
+    #[doc(hidden)]
+    fn into_bow(storage: Self::Storage) -> Bow<'a, T>;
+}
+
+/// State of a [`TypedBow`] owning its value.
+#[derive(Debug)]
+pub enum Owned {}
+
+/// State of a [`TypedBow`] borrowing its value.
+#[derive(Debug)]
+pub enum Borrowed {}
+
+impl sealed::Sealed for Owned {}
+impl sealed::Sealed for Borrowed {}
+
+impl<'a, T: 'a> State<'a, T> for Owned {
+    type Storage = T;
+
+    fn get(storage: &T) -> &T {
+        storage
+    }
+
+    fn into_bow(storage: T) -> Bow<'a, T> {
+        Bow::Owned(storage)
+    }
+}
+
+impl<'a, T: 'a> State<'a, T> for Borrowed {
+    type Storage = &'a T;
+
+    fn get<'b>(storage: &'b &'a T) -> &'b T {
+        storage
+    }
+
+    fn into_bow(storage: &'a T) -> Bow<'a, T> {
+        Bow::Borrowed(storage)
+    }
+}
+
+/// Borrow-Or-oWned smart pointer whose ownership state `S` is part of its
+/// type.
+pub struct TypedBow<'a, T: 'a, S: State<'a, T>> {
+    storage: S::Storage,
+}
+
+impl<'a, T: 'a> TypedBow<'a, T, Owned> {
+    /// Wrap an owned value.
+    pub fn owned(t: T) -> Self {
+        TypedBow { storage: t }
+    }
+
+    /// Consume the enclosed value and return it.
+    pub fn into_inner(this: Self) -> T {
+        this.storage
+    }
+}
+
+impl<'a, T: 'a> TypedBow<'a, T, Borrowed> {
+    /// Wrap a borrowed value.
+    pub fn borrowed(t: &'a T) -> Self {
+        TypedBow { storage: t }
+    }
+
+    /// Return the borrowed reference.
+    pub fn into_ref(this: Self) -> &'a T {
+        this.storage
+    }
+
+    /// Make an owned duplicate of the borrowed value with [`MakeOwned`].
+    pub fn into_owned(this: Self) -> TypedBow<'a, T, Owned>
+    where
+        T: MakeOwned,
+    {
+        TypedBow::owned(this.storage.make_owned())
+    }
+}
+
+impl<'a, T: 'a, S: State<'a, T>> Deref for TypedBow<'a, T, S> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        S::get(&self.storage)
+    }
+}
+
+/// Owned values can always be mutated.
+impl<'a, T: 'a> DerefMut for TypedBow<'a, T, Owned> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.storage
+    }
+}
+
+impl<'a, T: 'a, S: State<'a, T>> From<TypedBow<'a, T, S>> for Bow<'a, T> {
+    fn from(bow: TypedBow<'a, T, S>) -> Self {
+        S::into_bow(bow.storage)
+    }
+}
+
+impl<'a, T: 'a, S: State<'a, T>> Clone for TypedBow<'a, T, S>
+where
+    S::Storage: Clone,
+{
+    fn clone(&self) -> Self {
+        TypedBow {
+            storage: self.storage.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.storage.clone_from(&source.storage);
+    }
+}
+
+impl_deref_traits! {
+    ['a, T: 'a, S: State<'a, T>] TypedBow<'a, T, S> => T:
+        PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, AsRef
+}
+
+impl<'a, T: 'a> Default for TypedBow<'a, T, Owned>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        TypedBow::owned(T::default())
+    }
+}
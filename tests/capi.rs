@@ -0,0 +1,94 @@
+//! Check the C API through the same `extern "C"` declarations a C caller
+//! would use.
+#![cfg(feature = "capi")]
+extern crate boow;
+
+use std::ptr;
+use std::slice;
+
+/// Opaque handle, declared as C sees it.
+#[repr(C)]
+struct BowBytes {
+    _private: [u8; 0],
+}
+
+extern "C" {
+    fn boow_bytes_owned(data: *const u8, len: usize) -> *mut BowBytes;
+    fn boow_bytes_borrowed(data: *const u8, len: usize) -> *mut BowBytes;
+    fn boow_bytes_is_owned(bytes: *const BowBytes) -> bool;
+    fn boow_bytes_data(bytes: *const BowBytes) -> *const u8;
+    fn boow_bytes_len(bytes: *const BowBytes) -> usize;
+    fn boow_bytes_make_owned(bytes: *mut BowBytes);
+    fn boow_bytes_free(bytes: *mut BowBytes);
+}
+
+/// Copy the bytes of a handle.
+unsafe fn contents(bytes: *const BowBytes) -> Vec<u8> {
+    slice::from_raw_parts(boow_bytes_data(bytes), boow_bytes_len(bytes)).to_vec()
+}
+
+#[test]
+fn borrowed() {
+    let greeting = *b"hello";
+    unsafe {
+        let bytes = boow_bytes_borrowed(greeting.as_ptr(), greeting.len());
+        assert!(!boow_bytes_is_owned(bytes));
+        assert_eq!(boow_bytes_data(bytes), greeting.as_ptr());
+        assert_eq!(contents(bytes), b"hello");
+        boow_bytes_free(bytes);
+    }
+}
+
+#[test]
+fn owned() {
+    let mut greeting = *b"hello";
+    unsafe {
+        let bytes = boow_bytes_owned(greeting.as_ptr(), greeting.len());
+        greeting[0] = b'j';
+        assert!(boow_bytes_is_owned(bytes));
+        assert_ne!(boow_bytes_data(bytes), greeting.as_ptr());
+        assert_eq!(contents(bytes), b"hello");
+        boow_bytes_free(bytes);
+    }
+}
+
+#[test]
+fn make_owned() {
+    let greeting = b"hello".to_vec();
+    unsafe {
+        let bytes = boow_bytes_borrowed(greeting.as_ptr(), greeting.len());
+        boow_bytes_make_owned(bytes);
+        drop(greeting);
+        assert!(boow_bytes_is_owned(bytes));
+        assert_eq!(contents(bytes), b"hello");
+
+        let data = boow_bytes_data(bytes);
+        boow_bytes_make_owned(bytes);
+        assert_eq!(boow_bytes_data(bytes), data);
+        boow_bytes_free(bytes);
+    }
+}
+
+#[test]
+fn empty() {
+    unsafe {
+        for &bytes in &[
+            boow_bytes_borrowed(ptr::null(), 0),
+            boow_bytes_owned(ptr::null(), 0),
+        ] {
+            assert_eq!(boow_bytes_len(bytes), 0);
+            assert!(!boow_bytes_data(bytes).is_null());
+            assert!(contents(bytes).is_empty());
+            boow_bytes_make_owned(bytes);
+            assert_eq!(boow_bytes_len(bytes), 0);
+            boow_bytes_free(bytes);
+        }
+    }
+}
+
+#[test]
+fn free_null() {
+    unsafe {
+        boow_bytes_free(ptr::null_mut());
+    }
+}
@@ -0,0 +1,130 @@
+//! Check that `#[derive(ProjectBow)]` accepts the generic parameters a struct
+//! may declare, and projects each field into the variant of the input.
+#![cfg(feature = "derive")]
+extern crate boow;
+
+use boow::{Bow, ProjectBow};
+use std::fmt::Debug;
+
+#[derive(ProjectBow)]
+struct Named {
+    name: String,
+    retries: u32,
+}
+
+#[derive(ProjectBow)]
+struct Tuple(String, u32);
+
+#[derive(ProjectBow)]
+struct Bounded<I: Iterator<Item = u8>> {
+    iter: I,
+}
+
+#[derive(ProjectBow)]
+struct Defaulted<T: Clone = Vec<u8>, U = u32> {
+    value: T,
+    other: U,
+}
+
+#[derive(ProjectBow)]
+struct BoundedDefault<I: Iterator<Item = u8> = std::vec::IntoIter<u8>>(I);
+
+#[derive(ProjectBow)]
+struct Array<const N: usize> {
+    values: [u8; N],
+}
+
+#[derive(ProjectBow)]
+struct DefaultArray<const N: usize = 2>([u8; N]);
+
+#[derive(ProjectBow)]
+struct Where<'a, T>
+where
+    T: Debug + ?Sized,
+{
+    value: &'a T,
+    count: usize,
+}
+
+#[derive(ProjectBow)]
+struct TupleWhere<I>(I, u8)
+where
+    I: Iterator<Item = u8>;
+
+#[test]
+fn named() {
+    let named = Named {
+        name: "boow".to_owned(),
+        retries: 3,
+    };
+    let projection = Bow::project(Bow::Borrowed(&named));
+    assert!(Bow::is_borrowed(&projection.name));
+    assert_eq!(*projection.retries, 3);
+
+    let projection = Bow::project(Bow::Owned(named));
+    assert_eq!(Bow::extract(projection.name), Some("boow".to_owned()));
+    assert_eq!(Bow::extract(projection.retries), Some(3));
+}
+
+#[test]
+fn tuple() {
+    let tuple = Tuple("boow".to_owned(), 3);
+    let projection = Bow::project(Bow::Borrowed(&tuple));
+    assert!(Bow::is_borrowed(&projection.0));
+    assert_eq!(*projection.1, 3);
+
+    let TupleProjection(name, _) = Bow::project(Bow::Owned(tuple));
+    assert_eq!(Bow::extract(name), Some("boow".to_owned()));
+}
+
+#[test]
+fn associated_type_binding() {
+    let bounded = Bounded {
+        iter: vec![1u8, 2].into_iter(),
+    };
+    let projection = Bow::project(Bow::Owned(bounded));
+    let iter = Bow::extract(projection.iter).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2]);
+
+    let bounded: BoundedDefault = BoundedDefault(vec![3u8].into_iter());
+    let projection = Bow::project(Bow::Borrowed(&bounded));
+    assert_eq!(projection.0.len(), 1);
+}
+
+#[test]
+fn defaults() {
+    let defaulted: Defaulted = Defaulted {
+        value: vec![1],
+        other: 2,
+    };
+    let projection = Bow::project(Bow::Borrowed(&defaulted));
+    assert_eq!(*projection.value, [1]);
+    assert_eq!(*projection.other, 2);
+}
+
+#[test]
+fn const_generics() {
+    let array = Array { values: [1, 2, 3] };
+    let projection = Bow::project(Bow::Owned(array));
+    assert_eq!(Bow::extract(projection.values), Some([1, 2, 3]));
+
+    let array: DefaultArray = DefaultArray([4, 5]);
+    let projection = Bow::project(Bow::Borrowed(&array));
+    assert_eq!(*projection.0, [4, 5]);
+}
+
+#[test]
+fn where_clause() {
+    let with_where: Where<str> = Where {
+        value: "boow",
+        count: 1,
+    };
+    let projection = Bow::project(Bow::Borrowed(&with_where));
+    assert_eq!(*projection.value, "boow");
+    assert_eq!(*projection.count, 1);
+
+    let tuple = TupleWhere(vec![1u8].into_iter(), 2);
+    let TupleWhereProjection(iter, count) = Bow::project(Bow::Owned(tuple));
+    assert_eq!(Bow::extract(iter).unwrap().collect::<Vec<_>>(), [1]);
+    assert_eq!(*count, 2);
+}
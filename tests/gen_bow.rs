@@ -0,0 +1,118 @@
+//! Check that `GenBow` strings and slices compare with their std
+//! counterparts in both directions, whatever the variant, and that
+//! concatenation keeps borrowed values borrowed and owned buffers owned.
+//!
+//! Also check that strings, slices and paths take no more room than the
+//! owned value alone.
+extern crate boow;
+
+use boow::GenBow;
+use std::cmp::Ordering;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+/// Check that `lhs` compares to `rhs` as `ordering`, and `rhs` to `lhs` as
+/// its reverse.
+fn assert_cmp<L, R>(lhs: &L, rhs: &R, ordering: Ordering)
+where
+    L: PartialOrd<R> + ?Sized,
+    R: PartialOrd<L> + ?Sized,
+{
+    assert_eq!(lhs.eq(rhs), ordering == Ordering::Equal);
+    assert_eq!(rhs.eq(lhs), ordering == Ordering::Equal);
+    assert_eq!(lhs.partial_cmp(rhs), Some(ordering));
+    assert_eq!(rhs.partial_cmp(lhs), Some(ordering.reverse()));
+}
+
+fn strings() -> Vec<GenBow<'static, str, String>> {
+    vec![GenBow::Borrowed("boow"), GenBow::Owned("boow".to_owned())]
+}
+
+fn slices() -> Vec<GenBow<'static, [u8], Vec<u8>>> {
+    vec![GenBow::Borrowed(&[1, 2]), GenBow::Owned(vec![1, 2])]
+}
+
+#[test]
+fn str_cmp() {
+    for bow in strings() {
+        assert_cmp(&bow, "boow", Ordering::Equal);
+        assert_cmp(&bow, "cow", Ordering::Less);
+        assert_cmp(&bow, &"boow", Ordering::Equal);
+        assert_cmp(&bow, &"bon", Ordering::Greater);
+        assert_cmp(&bow, &"boow".to_owned(), Ordering::Equal);
+        assert_cmp(&bow, &"boox".to_owned(), Ordering::Less);
+    }
+}
+
+#[test]
+fn slice_cmp() {
+    for bow in slices() {
+        assert_cmp(&bow, &[1, 2][..], Ordering::Equal);
+        assert_cmp(&bow, &[1][..], Ordering::Greater);
+        assert_cmp(&bow, &&[1, 2][..], Ordering::Equal);
+        assert_cmp(&bow, &&[1, 3][..], Ordering::Less);
+        assert_cmp(&bow, &vec![1, 2], Ordering::Equal);
+        assert_cmp(&bow, &vec![0, 9], Ordering::Greater);
+    }
+}
+
+#[test]
+fn unordered_slice_cmp() {
+    let bow: GenBow<[f64], Vec<f64>> = GenBow::Borrowed(&[f64::NAN]);
+    assert_eq!(bow.partial_cmp(&vec![1.0]), None);
+    assert_eq!(vec![1.0].partial_cmp(&bow), None);
+}
+
+#[test]
+fn add_assign_keeps_owned_buffer() {
+    let mut bow = GenBow::<str, String>::with_capacity(64);
+    bow += "boow";
+    assert!(bow.capacity() >= 64);
+    assert_eq!(GenBow::extract(bow), Some("boow".to_owned()));
+
+    let mut bow = GenBow::<str, String>::with_capacity(64);
+    bow += GenBow::Borrowed("boow");
+    assert!(bow.capacity() >= 64);
+    assert_eq!(GenBow::extract(bow), Some("boow".to_owned()));
+}
+
+#[test]
+fn add_assign_borrows_into_borrowed_empty() {
+    let mut bow = GenBow::<str, String>::EMPTY;
+    bow += "boow";
+    bow += "";
+    assert_eq!(bow, GenBow::Borrowed("boow"));
+    bow += GenBow::Borrowed("");
+    assert!(GenBow::extract(bow).is_none());
+}
+
+#[test]
+fn three_words() {
+    let words = 3 * mem::size_of::<usize>();
+    assert_eq!(mem::size_of::<GenBow<str, String>>(), words);
+    assert_eq!(mem::size_of::<GenBow<[u8], Vec<u8>>>(), words);
+    assert_eq!(mem::size_of::<GenBow<Path, PathBuf>>(), words);
+}
+
+#[test]
+fn join_skips_empty_parts() {
+    let parts = vec![GenBow::Borrowed(&b"boow"[..]), GenBow::Borrowed(&[][..])];
+    let joined = GenBow::join(parts.clone(), &[]);
+    assert_eq!(joined, GenBow::Borrowed(&b"boow"[..]));
+    assert_eq!(
+        GenBow::extract(GenBow::join(parts, b",")),
+        Some(b"boow,".to_vec())
+    );
+}
+
+#[test]
+fn collect_skips_empty_fragments() {
+    let collected: GenBow<str, String> = vec!["boow", ""].into_iter().collect();
+    assert_eq!(collected, GenBow::Borrowed("boow"));
+    let collected: GenBow<str, String> = vec!["", "boow"].into_iter().collect();
+    assert_eq!(collected, GenBow::Borrowed("boow"));
+
+    let collected: GenBow<[u8], Vec<u8>> =
+        vec![&[][..], &b"boow"[..], &[][..]].into_iter().collect();
+    assert_eq!(collected, GenBow::Borrowed(&b"boow"[..]));
+}
@@ -0,0 +1,44 @@
+//! Check that `Bow` can be dereferenced and compared in `const` contexts
+//! with the `nightly-const` feature.
+#![cfg_attr(
+    feature = "nightly-const",
+    feature(const_trait_impl, const_convert, const_cmp)
+)]
+#![cfg(feature = "nightly-const")]
+extern crate boow;
+
+use boow::Bow;
+use std::cmp::Ordering;
+
+const DEFAULT: u32 = 3;
+const BORROWED: Bow<'static, u32> = Bow::borrowed(&DEFAULT);
+const OWNED: Bow<'static, u32> = Bow::owned(3);
+
+const DEREF: u32 = *BORROWED;
+const EQ: bool = BORROWED == OWNED;
+const NE: bool = Bow::owned(4u32) != OWNED;
+const CMP: Ordering = Ord::cmp(&Bow::owned(1u32), &BORROWED);
+const PARTIAL_CMP: Option<Ordering> = PartialOrd::partial_cmp(&OWNED, &Bow::owned(5u32));
+
+/// Lookup table built at compile time, only keeping the entries equal to
+/// the borrowed default.
+const TABLE: [bool; 3] = {
+    let entries = [Bow::owned(2u32), Bow::borrowed(&DEFAULT), Bow::owned(3)];
+    let mut table = [false; 3];
+    let mut i = 0;
+    while i < entries.len() {
+        table[i] = entries[i] == BORROWED;
+        i += 1;
+    }
+    table
+};
+
+#[test]
+fn const_deref_and_comparisons() {
+    assert_eq!(DEREF, 3);
+    assert!(EQ);
+    assert!(NE);
+    assert_eq!(CMP, Ordering::Less);
+    assert_eq!(PARTIAL_CMP, Some(Ordering::Less));
+    assert_eq!(TABLE, [false, true, true]);
+}
@@ -0,0 +1,139 @@
+//! Check that `SmallBow` drops every owned value exactly once, whether it is
+//! stored inline, boxed, or moved out with `SmallBow::extract`, and that its
+//! size stays bounded.
+//!
+//! These tests exercise the unsafe inline storage and are meant to be run
+//! under Miri as well: `cargo +nightly miri test --test small_bow`.
+extern crate boow;
+
+use boow::SmallBow;
+use std::cell::Cell;
+use std::mem;
+
+/// Count how many times values sharing the same counter are dropped.
+struct DropCounter<'c> {
+    drops: &'c Cell<usize>,
+    value: u8,
+}
+
+impl<'c> DropCounter<'c> {
+    fn new(drops: &'c Cell<usize>, value: u8) -> Self {
+        DropCounter { drops, value }
+    }
+}
+
+impl<'c> Clone for DropCounter<'c> {
+    fn clone(&self) -> Self {
+        DropCounter::new(self.drops, self.value)
+    }
+}
+
+impl<'c> Drop for DropCounter<'c> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+/// Too big to be stored inline in a single word.
+struct Big<'c>(DropCounter<'c>, [u64; 4]);
+
+/// More aligned than a machine word, so never stored inline.
+#[repr(align(64))]
+struct OverAligned<'c>(DropCounter<'c>);
+
+#[test]
+fn owned_inline_is_dropped_once() {
+    let drops = Cell::new(0);
+    let bow: SmallBow<DropCounter, 2> = SmallBow::owned(DropCounter::new(&drops, 1));
+    assert!(SmallBow::is_inline(&bow));
+    assert_eq!(bow.value, 1);
+    drop(bow);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn owned_spilled_is_dropped_once() {
+    let drops = Cell::new(0);
+    let bow: SmallBow<Big, 1> = SmallBow::owned(Big(DropCounter::new(&drops, 2), [7; 4]));
+    assert!(SmallBow::is_owned(&bow));
+    assert!(!SmallBow::is_inline(&bow));
+    assert_eq!(bow.1, [7; 4]);
+    drop(bow);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn borrowed_is_not_dropped() {
+    let drops = Cell::new(0);
+    let counter = DropCounter::new(&drops, 3);
+    let bow: SmallBow<DropCounter, 2> = SmallBow::borrowed(&counter);
+    assert!(SmallBow::is_borrowed(&bow));
+    assert!(SmallBow::extract(bow.clone()).is_none());
+    drop(bow);
+    assert_eq!(drops.get(), 0);
+    drop(counter);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn extract_inline_then_drop_once() {
+    let drops = Cell::new(0);
+    let bow: SmallBow<DropCounter, 2> = SmallBow::owned(DropCounter::new(&drops, 4));
+    let counter = SmallBow::extract(bow).unwrap();
+    assert_eq!(drops.get(), 0);
+    assert_eq!(counter.value, 4);
+    drop(counter);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn extract_spilled_then_drop_once() {
+    let drops = Cell::new(0);
+    let bow: SmallBow<Big, 1> = SmallBow::owned(Big(DropCounter::new(&drops, 5), [0; 4]));
+    let big = SmallBow::extract(bow).unwrap();
+    assert_eq!(drops.get(), 0);
+    assert_eq!(big.0.value, 5);
+    drop(big);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn over_aligned_is_boxed_and_dropped_once() {
+    let drops = Cell::new(0);
+    const { assert!(!SmallBow::<OverAligned, 16>::FITS_INLINE) };
+    let bow: SmallBow<OverAligned, 16> = SmallBow::owned(OverAligned(DropCounter::new(&drops, 6)));
+    assert!(!SmallBow::is_inline(&bow));
+    assert_eq!(&*bow as *const OverAligned as usize % 64, 0);
+    let aligned = SmallBow::extract(bow).unwrap();
+    assert_eq!(drops.get(), 0);
+    assert_eq!(aligned.0.value, 6);
+    drop(aligned);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn clone_from_and_mutation_keep_drop_count() {
+    let drops = Cell::new(0);
+    {
+        let mut bow: SmallBow<DropCounter, 2> = SmallBow::owned(DropCounter::new(&drops, 7));
+        SmallBow::borrow_mut(&mut bow).unwrap().value = 8;
+        let source: SmallBow<DropCounter, 2> = SmallBow::owned(DropCounter::new(&drops, 9));
+        bow.clone_from(&source);
+        assert_eq!(bow.value, 9);
+        let into_owned = SmallBow::into_owned(bow);
+        assert_eq!(into_owned.value, 9);
+    }
+    // The original value of `bow` (dropped by `clone_from`), `source`, and
+    // the value moved out by `into_owned`.
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn size_is_bounded_by_words_or_pointer() {
+    let word = mem::size_of::<usize>();
+    assert_eq!(mem::size_of::<SmallBow<[u64; 8], 0>>(), 2 * word);
+    assert_eq!(mem::size_of::<SmallBow<[u64; 8], 2>>(), 3 * word);
+
+    assert!(SmallBow::is_inline(&SmallBow::<(), 0>::owned(())));
+    assert!(!SmallBow::is_inline(&SmallBow::<u8, 0>::owned(1)));
+}
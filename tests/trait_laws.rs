@@ -0,0 +1,216 @@
+//! Check that comparison, hashing and dereferencing through a `Bow` are
+//! consistent with the enclosed value, whatever the variant.
+//!
+//! Values are generated by a small deterministic pseudo-random generator, so
+//! that failures are reproducible.
+extern crate boow;
+
+use boow::typestate::TypedBow;
+use boow::{Bow, BowMutSlice, BowOrBox, GenBow, GuardBow, PinBow, SharedBow, SmallBow};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const CASES: usize = 256;
+
+/// Xorshift generator.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn i32(&mut self) -> i32 {
+        // Narrow the range to get equal values now and then.
+        (self.next() % 16) as i32 - 8
+    }
+
+    fn string(&mut self) -> String {
+        let len = self.next() % 4;
+        (0..len)
+            .map(|_| (b'a' + (self.next() % 3) as u8) as char)
+            .collect()
+    }
+
+    fn vec(&mut self) -> Vec<i32> {
+        let len = self.next() % 4;
+        (0..len).map(|_| self.i32()).collect()
+    }
+}
+
+fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Check the laws for a wrapper `W` of `T` against `T` itself.
+fn check<T, W>(a: &T, b: &T, wa: &W, wb: &W)
+where
+    T: Ord + Hash + Debug + ?Sized,
+    W: Ord + Hash + Debug + Deref<Target = T>,
+{
+    assert_eq!(wa == wb, a == b, "{:?} == {:?}", wa, wb);
+    assert_eq!(wa.cmp(wb), a.cmp(b), "{:?} cmp {:?}", wa, wb);
+    assert_eq!(
+        wa.partial_cmp(wb),
+        a.partial_cmp(b),
+        "{:?} partial_cmp {:?}",
+        wa,
+        wb
+    );
+    assert_eq!(hash(wa), hash(a), "hash of {:?}", wa);
+    assert_eq!(&**wa, a, "deref of {:?}", wa);
+}
+
+fn bows<T: Clone>(t: &T) -> Vec<Bow<'_, T>> {
+    vec![Bow::Owned(t.clone()), Bow::Borrowed(t)]
+}
+
+fn bow_or_boxes<T: Clone>(t: &T) -> Vec<BowOrBox<'_, T>> {
+    vec![
+        BowOrBox::Owned(t.clone()),
+        BowOrBox::Borrowed(t),
+        BowOrBox::Boxed(Box::new(t.clone())),
+    ]
+}
+
+fn small_bows<T: Clone>(t: &T) -> Vec<SmallBow<'_, T, 1>> {
+    vec![SmallBow::owned(t.clone()), SmallBow::borrowed(t)]
+}
+
+fn guard_bows<T: Clone>(t: &T) -> Vec<GuardBow<'_, T>> {
+    vec![
+        GuardBow::Owned(t.clone()),
+        GuardBow::Borrowed(t),
+        GuardBow::guarded(Box::new(t.clone())),
+    ]
+}
+
+fn shared_bows<T: Clone>(t: &T) -> Vec<SharedBow<'_, T>> {
+    vec![
+        SharedBow::Owned(t.clone()),
+        SharedBow::Borrowed(t),
+        SharedBow::Shared(Arc::new(t.clone())),
+    ]
+}
+
+fn pin_bows<T: Clone + Unpin>(t: &T) -> Vec<PinBow<'_, T>> {
+    vec![PinBow::pin(t.clone()), PinBow::Borrowed(Pin::new(t))]
+}
+
+fn gen_bows(s: &str) -> Vec<GenBow<'_, str>> {
+    vec![GenBow::Owned(s.to_owned()), GenBow::Borrowed(s)]
+}
+
+fn check_all<T, F>(mut gen: F)
+where
+    T: Ord + Hash + Debug + Clone + Unpin,
+    F: FnMut(&mut Rng) -> T,
+{
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..CASES {
+        let a = gen(&mut rng);
+        let b = gen(&mut rng);
+        for wa in &bows(&a) {
+            for wb in &bows(&b) {
+                check(&a, &b, wa, wb);
+            }
+        }
+        for wa in &bow_or_boxes(&a) {
+            for wb in &bow_or_boxes(&b) {
+                check(&a, &b, wa, wb);
+            }
+        }
+        for wa in &small_bows(&a) {
+            for wb in &small_bows(&b) {
+                check(&a, &b, wa, wb);
+            }
+        }
+        for wa in &guard_bows(&a) {
+            for wb in &guard_bows(&b) {
+                check(&a, &b, wa, wb);
+            }
+        }
+        for wa in &shared_bows(&a) {
+            for wb in &shared_bows(&b) {
+                check(&a, &b, wa, wb);
+            }
+        }
+        for wa in &pin_bows(&a) {
+            for wb in &pin_bows(&b) {
+                check(&a, &b, wa, wb);
+            }
+        }
+        check(
+            &a,
+            &b,
+            &TypedBow::owned(a.clone()),
+            &TypedBow::owned(b.clone()),
+        );
+        check(&a, &b, &TypedBow::borrowed(&a), &TypedBow::borrowed(&b));
+    }
+}
+
+#[test]
+fn integers() {
+    check_all(Rng::i32);
+}
+
+#[test]
+fn strings() {
+    check_all(Rng::string);
+}
+
+#[test]
+fn vectors() {
+    check_all(Rng::vec);
+}
+
+#[test]
+fn tuples() {
+    check_all(|rng| (rng.i32(), rng.string()));
+}
+
+#[test]
+fn unsized_gen_bows() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..CASES {
+        let a = rng.string();
+        let b = rng.string();
+        for wa in &gen_bows(&a) {
+            for wb in &gen_bows(&b) {
+                check(a.as_str(), b.as_str(), wa, wb);
+            }
+        }
+    }
+}
+
+#[test]
+fn mutable_slices() {
+    let mut rng = Rng(0x27d4_eb2f_1656_67c5);
+    for _ in 0..CASES {
+        let a = rng.vec();
+        let b = rng.vec();
+        let (mut ca, mut cb) = (a.clone(), b.clone());
+        check(
+            &a[..],
+            &b[..],
+            &BowMutSlice::Borrowed(&mut ca),
+            &BowMutSlice::Borrowed(&mut cb),
+        );
+        check(
+            &a[..],
+            &b[..],
+            &BowMutSlice::Owned(a.clone()),
+            &BowMutSlice::Owned(b.clone()),
+        );
+    }
+}